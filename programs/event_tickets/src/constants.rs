@@ -27,6 +27,129 @@ pub const TICKET_MINT_SEED: &[u8] = b"ticket_mint";
 #[constant]
 pub const TICKET_OWNERSHIP_SEED: &[u8] = b"ticket_ownership";
 
+/// Seed for the event's seat map PDA.
+#[constant]
+pub const SEAT_MAP_SEED: &[u8] = b"seat_map";
+
+/// The maximum number of sections in a seat map.
+#[constant]
+pub const MAX_SEAT_SECTIONS: u8 = 32;
+
+/// Seed for the event's lottery PDA.
+#[constant]
+pub const LOTTERY_SEED: &[u8] = b"lottery";
+
+/// Seed for a lottery entry PDA.
+#[constant]
+pub const LOTTERY_ENTRY_SEED: &[u8] = b"lottery_entry";
+
+/// The number of slots after its draw slot during which a lottery with a commitment can
+/// only be drawn with the admin's secret. Afterwards anyone can draw it without the secret.
+#[constant]
+pub const LOTTERY_REVEAL_WINDOW_SLOTS: u64 = 256;
+
+/// The number of slots after its draw slot during which a lottery can be drawn. The draw
+/// slot's hash must still be in the SlotHashes sysvar, which keeps the last 512 slots.
+#[constant]
+pub const LOTTERY_DRAW_WINDOW_SLOTS: u64 = 500;
+
+/// Seed for the event's waitlist PDA.
+#[constant]
+pub const WAITLIST_SEED: &[u8] = b"waitlist";
+
+/// Seed for a waitlist entry PDA.
+#[constant]
+pub const WAITLIST_ENTRY_SEED: &[u8] = b"waitlist_entry";
+
+/// Seed for a promo code PDA.
+#[constant]
+pub const PROMO_CODE_SEED: &[u8] = b"promo_code";
+
+/// Seed for the event's ticket registry PDA.
+#[constant]
+pub const TICKET_REGISTRY_SEED: &[u8] = b"ticket_registry";
+
+/// The number of tickets a ticket registry grows by when it runs out of room.
+#[constant]
+pub const TICKET_REGISTRY_GROWTH: u64 = 1024;
+
+/// The time after an event's end during which only the admin can close its tickets,
+/// after which anyone can, in seconds.
+#[constant]
+pub const CLEANUP_GRACE_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+/// The lamports paid from a ticket's reclaimed rent to whoever cleans it up after the grace period.
+#[constant]
+pub const CLEANUP_BOUNTY: u64 = 100_000;
+
+/// Seed for the installment reservation PDA.
+#[constant]
+pub const RESERVATION_SEED: &[u8] = b"reservation";
+
+/// The maximum number of installments a ticket price can be split into.
+#[constant]
+pub const MAX_INSTALLMENTS: u8 = 12;
+
+/// Seed for the loyalty record PDA of a wallet with an organizer.
+#[constant]
+pub const LOYALTY_SEED: &[u8] = b"loyalty";
+
+/// Seed for the archive PDA left behind when an event is settled.
+#[constant]
+pub const EVENT_ARCHIVE_SEED: &[u8] = b"event_archive";
+
+/// Number of slots a gatekeeper token stays valid after the slot it was issued for.
+#[constant]
+pub const GATEKEEPER_TOKEN_MAX_AGE_SLOTS: u64 = 150;
+
+/// Seed for the gatekeeper pass PDA of a buyer at an event.
+#[constant]
+pub const GATEKEEPER_PASS_SEED: &[u8] = b"gatekeeper_pass";
+
 /// Seed for the extra accounts metas PDA.
 #[constant]
 pub const EXTRA_ACCOUNTS_METAS_SEED: &[u8] = b"extra-account-metas";
+
+/// Ticket NFT metadata key holding the ticket's lifecycle status.
+#[constant]
+pub const METADATA_STATUS_KEY: &str = "status";
+
+/// Ticket NFT metadata key holding the Unix timestamp of the check-in.
+#[constant]
+pub const METADATA_CHECKED_IN_AT_KEY: &str = "checked_in_at";
+
+/// Ticket NFT metadata key holding the ID of the event the ticket belongs to.
+#[constant]
+pub const METADATA_EVENT_ID_KEY: &str = "event_id";
+
+/// Ticket NFT metadata key holding the label of the reserved seat.
+#[constant]
+pub const METADATA_SEAT_KEY: &str = "seat";
+
+/// Ticket NFT metadata key holding the name of the venue.
+#[constant]
+pub const METADATA_VENUE_KEY: &str = "venue";
+
+/// Ticket NFT metadata key holding the geohash of the venue.
+#[constant]
+pub const METADATA_GEOHASH_KEY: &str = "geohash";
+
+/// Ticket NFT metadata key holding the category of the event.
+#[constant]
+pub const METADATA_CATEGORY_KEY: &str = "category";
+
+/// Ticket NFT metadata key holding the minimum age of attendees.
+#[constant]
+pub const METADATA_MIN_AGE_KEY: &str = "min_age";
+
+/// Ticket NFT metadata key holding the UTC offset of the venue, in minutes.
+#[constant]
+pub const METADATA_UTC_OFFSET_KEY: &str = "utc_offset_minutes";
+
+/// Metadata status of a ticket that can still be used.
+#[constant]
+pub const TICKET_STATUS_VALID: &str = "valid";
+
+/// Metadata status of a ticket that has been checked in.
+#[constant]
+pub const TICKET_STATUS_USED: &str = "used";
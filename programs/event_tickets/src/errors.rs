@@ -14,15 +14,45 @@ pub enum EventError {
     DescriptionTooLong,
     #[msg("Metadata URI cannot exceed 200 characters.")]
     UriTooLong,
+    #[msg("URI templates only support the id placeholder, and the tier and seat placeholders for reserved seating.")]
+    InvalidUriTemplate,
     #[msg("The event's end time must be after its start time.")]
     InvalidEventTime,
     #[msg("The total number of tickets must be greater than zero.")]
     InvalidTicketCount,
+    #[msg("Venue name cannot exceed 64 characters.")]
+    VenueTooLong,
+    #[msg("The venue location must be a geohash of at most 12 characters.")]
+    InvalidGeohash,
+    #[msg("The minimum age cannot exceed 99.")]
+    InvalidMinimumAge,
+    #[msg("The UTC offset must be a multiple of 15 minutes between -12:00 and +14:00.")]
+    InvalidUtcOffset,
     #[msg("This event has already ended.")]
     EventEnded,
     #[msg("This action can only be performed after the event has ended.")]
     EventNotEnded,
 
+    // Event status errors
+    #[msg("This event is a draft and has not been published yet.")]
+    EventNotPublished,
+    #[msg("Ticket sales for this event are closed.")]
+    SalesClosed,
+    #[msg("This event has been cancelled.")]
+    EventCancelled,
+    #[msg("This event has already been settled.")]
+    EventSettled,
+    #[msg("The event cannot move from its current status to the requested one.")]
+    InvalidStatusTransition,
+    #[msg("The event status is already up to date.")]
+    EventStatusUnchanged,
+    #[msg("A cancelled event can only be settled once every ticket has been returned.")]
+    TicketsOutstanding,
+    #[msg("This action can only be performed on a draft event.")]
+    EventNotDraft,
+    #[msg("Exactly one of the event and its archive must be provided.")]
+    InvalidEventAccounts,
+
     // Ticket minting errors
     #[msg("The ticket price must be greater than zero.")]
     InvalidTicketPrice,
@@ -37,6 +67,214 @@ pub enum EventError {
     #[msg("The signer is not the owner of this ticket.")]
     TicketHolderMismatch,
 
+    // Seating errors
+    #[msg("A seat map must have between 1 and 32 sections.")]
+    InvalidSectionCount,
+    #[msg("Seat map section names must be between 1 and 16 characters long.")]
+    InvalidSectionName,
+    #[msg("Every seat map section must have at least one row and one seat per row.")]
+    InvalidSectionSize,
+    #[msg("The seat map must contain exactly as many seats as the event has tickets.")]
+    SeatCountMismatch,
+    #[msg("The seat map can only be configured before any ticket is sold.")]
+    TicketsAlreadySold,
+    #[msg("This event has reserved seating; a seat and the seat map must be provided.")]
+    SeatRequired,
+    #[msg("This event does not have reserved seating.")]
+    SeatingNotEnabled,
+    #[msg("The selected seat does not exist in the seat map.")]
+    InvalidSeat,
+    #[msg("The selected seat has already been sold.")]
+    SeatAlreadySold,
+
+    // Pricing errors
+    #[msg("The auction start price must be greater than its floor price, which must be greater than zero.")]
+    InvalidAuctionPrice,
+    #[msg("The auction window must be valid and end before the event ends.")]
+    InvalidAuctionTime,
+    #[msg("The auction half-life must be greater than zero.")]
+    InvalidDecayCurve,
+    #[msg("The auction has not started yet.")]
+    AuctionNotStarted,
+    #[msg("The auction has not ended yet.")]
+    AuctionNotEnded,
+    #[msg("This event is not sold by Dutch auction.")]
+    NotAnAuction,
+    #[msg("This auction does not offer rebates to the clearing price.")]
+    RebateNotEnabled,
+    #[msg("There is no rebate to claim for this ticket.")]
+    NothingToRebate,
+    #[msg("The demand curve step and increase must be greater than zero.")]
+    InvalidDemandCurve,
+    #[msg("The current ticket price exceeds the buyer's maximum price.")]
+    PriceAboveMaximum,
+
+    // Lottery errors
+    #[msg("The lottery windows must be ordered and end before the event ends.")]
+    InvalidLotteryTime,
+    #[msg("The number of lottery winners must be between 1 and the total number of tickets.")]
+    InvalidWinnerCount,
+    #[msg("The lottery is not open for entries.")]
+    LotteryEntryClosed,
+    #[msg("The lottery entry period has not ended yet.")]
+    LotteryEntryOpen,
+    #[msg("The lottery winners have already been drawn.")]
+    LotteryAlreadyDrawn,
+    #[msg("The lottery winners have not been drawn yet.")]
+    LotteryNotDrawn,
+    #[msg("The lottery has no entries to draw from.")]
+    NoLotteryEntries,
+    #[msg("The revealed secret does not match the lottery commitment.")]
+    InvalidLotterySecret,
+    #[msg("The lottery draw slot must be in the future.")]
+    InvalidLotteryDrawSlot,
+    #[msg("The lottery draw slot has not passed yet.")]
+    LotteryDrawSlotNotReached,
+    #[msg(
+        "The lottery can no longer be drawn, as the hash of its draw slot is no longer available."
+    )]
+    LotteryDrawExpired,
+    #[msg("This event sells tickets by lottery; the lottery account must be provided.")]
+    LotteryRequired,
+    #[msg("Only lottery winners can buy tickets during the claim window.")]
+    NotALotteryWinner,
+    #[msg("The lottery entry does not belong to this lottery or buyer.")]
+    LotteryEntryMismatch,
+    #[msg("The lottery entrant must sign to buy a ticket with their entry.")]
+    LotteryEntrantNotSigner,
+    #[msg("This lottery deposit cannot be refunded yet.")]
+    LotteryRefundUnavailable,
+
+    // Waitlist errors
+    #[msg("The waitlist claim window must be greater than zero.")]
+    InvalidClaimWindow,
+    #[msg("The waitlist deposit must be greater than zero.")]
+    InvalidWaitlistDeposit,
+    #[msg("This event has a waitlist; the waitlist account must be provided.")]
+    WaitlistRequired,
+    #[msg("There is no reserved ticket to offer to the waitlist.")]
+    NoOpenWaitlistSlot,
+    #[msg("The waitlist entry does not belong to this waitlist or buyer.")]
+    WaitlistEntryMismatch,
+    #[msg("This waitlist entry is not in the expected state.")]
+    InvalidWaitlistStatus,
+    #[msg("The waitlist offer has expired.")]
+    WaitlistOfferExpired,
+    #[msg("The waitlist offer has not expired yet.")]
+    WaitlistOfferActive,
+    #[msg("The waitlist deposit does not cover the ticket price.")]
+    WaitlistDepositTooLow,
+    #[msg("This waitlist deposit cannot be refunded yet.")]
+    WaitlistRefundUnavailable,
+
+    // Promo code errors
+    #[msg("The promo code discount must be greater than zero and at most 100%.")]
+    InvalidDiscount,
+    #[msg("The promo code must allow at least one redemption and have a valid window.")]
+    InvalidPromoCodeConfig,
+    #[msg("The promo code account must be provided together with the code.")]
+    PromoCodeRequired,
+    #[msg("The promo code is not valid for this event.")]
+    InvalidPromoCode,
+    #[msg("The promo code is not active at this time.")]
+    PromoCodeInactive,
+    #[msg("The promo code has reached its maximum number of redemptions.")]
+    PromoCodeExhausted,
+    #[msg("The promo code cannot be used for this seat.")]
+    PromoCodeNotApplicable,
+
+    // Lite ticket errors
+    #[msg("The lite registry must have room for every ticket of the event.")]
+    LiteRegistryTooSmall,
+    #[msg("The lite registry has no room for more tickets.")]
+    LiteRegistryFull,
+    #[msg("Tickets for this event are issued in its lite registry, not as NFTs.")]
+    LiteModeEnabled,
+    #[msg("This event does not issue lite tickets.")]
+    LiteModeNotEnabled,
+    #[msg("Lite tickets cannot be combined with seating, lotteries or waitlists.")]
+    LiteModeUnsupported,
+    #[msg("The lite ticket is not valid.")]
+    InvalidLiteTicket,
+
+    // Ticket registry errors
+    #[msg("The ticket registry account must be provided for this event.")]
+    TicketRegistryRequired,
+    #[msg("The ticket is not tracked by the ticket registry.")]
+    TicketNotInRegistry,
+    #[msg("The ticket has not been sold.")]
+    TicketNotSold,
+    #[msg("The ticket has been returned or closed.")]
+    TicketNotActive,
+    #[msg("The ticket accounts must be groups of ticket, mint, ownership, token account and rent recipient.")]
+    InvalidTicketAccounts,
+    #[msg("Only the admin can close tickets until the grace period after the event has passed.")]
+    CleanupGracePeriodActive,
+
+    // Installment errors
+    #[msg("The event does not sell tickets in installments.")]
+    InstallmentsNotEnabled,
+    #[msg("The installment plan needs a deposit, 1 to 12 installments and a positive interval.")]
+    InvalidInstallmentPlan,
+    #[msg("Installments are not available for events with reserved seating, lotteries, waitlists, auctions or lite tickets.")]
+    InstallmentsUnsupported,
+    #[msg("The installment schedule would end after the event starts.")]
+    InstallmentScheduleTooLong,
+    #[msg("The reservation does not belong to this buyer or event.")]
+    ReservationMismatch,
+    #[msg("The reservation has not been fully paid.")]
+    ReservationNotPaid,
+    #[msg("The reservation has already been fully paid.")]
+    ReservationAlreadyPaid,
+    #[msg("The installment is overdue; the reservation can only be cancelled.")]
+    InstallmentOverdue,
+    #[msg("The reservation can only be cancelled after a missed installment or once the event has ended or been cancelled.")]
+    ReservationNotCancellable,
+    #[msg("Reservations must be claimed or cancelled before the funds can be withdrawn.")]
+    ReservationsOutstanding,
+
+    // Loyalty errors
+    #[msg("The loyalty program needs a minimum attendance and a discount or a presale ending before the event starts.")]
+    InvalidLoyaltyProgram,
+    #[msg("Only returning attendees can buy tickets during the loyalty presale.")]
+    LoyaltyPresaleOnly,
+
+    // Lending errors
+    #[msg("The ticket is lent out and cannot be transferred or returned until it is reclaimed.")]
+    TicketOnLoan,
+    #[msg("The ticket is not lent out.")]
+    TicketNotLent,
+    #[msg("The loan must end in the future and before the ticket expires.")]
+    InvalidLoanDeadline,
+    #[msg("The ticket can only be reclaimed after the loan deadline or once it has been used.")]
+    LoanActive,
+    #[msg("The borrower's token account holding the ticket must be provided.")]
+    BorrowerTicketAccountRequired,
+
+    // Attestation errors
+    #[msg("A valid attestation from the event's issuer is required to buy tickets.")]
+    AttestationRequired,
+    #[msg("The attestation does not make the claim required by the event.")]
+    AttestationClaimMismatch,
+    #[msg("The attestation has expired.")]
+    AttestationExpired,
+
+    // Gatekeeper errors
+    #[msg("The purchase must be co-signed by the event's gatekeeper or carry a gatekeeper token.")]
+    GatekeeperApprovalRequired,
+    #[msg("The gatekeeper token was not issued for a recent slot.")]
+    GatekeeperTokenExpired,
+    #[msg("The gatekeeper token has already been used.")]
+    GatekeeperTokenUsed,
+    #[msg("Purchases approved with a gatekeeper token must include the buyer's gatekeeper pass.")]
+    GatekeeperPassRequired,
+
+    // Rent errors
+    #[msg("The rent recipient does not match the ticket's rent payer or holder.")]
+    RentRecipientMismatch,
+    #[msg("Only the wallet that paid the ticket's rent can change where it is refunded.")]
+    NotRentPayer,
+
     // General Errors
     #[msg("A numeric operation resulted in an overflow.")]
     NumericOverflow,
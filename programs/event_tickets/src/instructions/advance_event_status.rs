@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::EVENT_SEED, errors::EventError, state::Event};
+
+/// Contextual accounts required to record the time-based status of an event.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct AdvanceEventStatus<'info> {
+    /// The event whose status is being advanced.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// Anyone can advance the status of an event.
+    pub cranker: Signer<'info>,
+}
+
+/// Handles the logic for recording the time-based transitions of an event.
+///
+/// This instruction is permissionless: once `start_time` or `end_time` has passed,
+/// anyone can record that the event is live or has ended.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn advance_event_status_handler(
+    ctx: Context<AdvanceEventStatus>,
+    _event_id: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let event = &mut ctx.accounts.event;
+
+    let status = event.status_at(clock.unix_timestamp);
+    require!(status != event.status, EventError::EventStatusUnchanged);
+
+    event.status = status;
+
+    msg!("Event {} status advanced", event.id);
+
+    Ok(())
+}
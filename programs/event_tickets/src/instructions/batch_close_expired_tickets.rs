@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::{EVENT_ARCHIVE_SEED, EVENT_SEED, EXTRA_ACCOUNTS_METAS_SEED, TICKET_SEED},
+    errors::EventError,
+    state::{EndedEvent, Event, EventArchive, Ticket, TicketOwnership, TicketRegistry},
+    utils::{burn_and_close_ticket_mint, close_program_account},
+};
+
+/// The number of remaining accounts describing one ticket: the ticket, its mint, its
+/// ownership record, its mint's `ExtraAccountMetaList`, the token account holding it and
+/// the wallet receiving its rent.
+const ACCOUNTS_PER_TICKET: usize = 6;
+
+/// Contextual accounts required for an admin to close many expired tickets at once.
+///
+/// The tickets are passed through the remaining accounts, in groups of
+/// (ticket, mint, ownership, extra account metas, token account, rent recipient), all writable.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct BatchCloseExpiredTickets<'info> {
+    /// The event account. Omitted once the event has been settled, in favor of its archive.
+    #[account(
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Option<Box<Account<'info, Event>>>,
+
+    /// The archive of the event, provided instead of the event once it has been settled.
+    #[account(
+        seeds = [EVENT_ARCHIVE_SEED, event_archive.event.as_ref()],
+        bump = event_archive.bump,
+        has_one = admin @ EventError::AuthorityMismatch,
+        constraint = event_archive.id == event_id @ EventError::InvalidEventAccounts,
+    )]
+    pub event_archive: Option<Account<'info, EventArchive>>,
+
+    /// The event administrator. Must be a signer and match the admin of the event,
+    /// which the event's seeds and the archive's `admin` enforce.
+    pub admin: Signer<'info>,
+
+    /// The event's ticket registry, where the closes are recorded.
+    /// Required when the event tracks ticket statuses in a registry.
+    #[account(mut)]
+    pub ticket_registry: Option<AccountLoader<'info, TicketRegistry>>,
+
+    /// The SPL Token 2022 program.
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Handles the logic for closing many expired tickets in one instruction.
+///
+/// Each ticket is closed like in `close_expired_ticket`. Tickets that were already
+/// returned or closed are skipped, so a batch that overlaps an earlier one still succeeds.
+/// Once the event has been settled, its archive is passed instead of the event.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn batch_close_expired_tickets_handler<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BatchCloseExpiredTickets<'info>>,
+    _event_id: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let event = EndedEvent::resolve(
+        ctx.accounts.event.as_deref(),
+        ctx.accounts.event_archive.as_ref(),
+        clock.unix_timestamp,
+    )?;
+    let groups = ctx.remaining_accounts.chunks_exact(ACCOUNTS_PER_TICKET);
+    require!(
+        groups.remainder().is_empty(),
+        EventError::InvalidTicketAccounts
+    );
+
+    let event_key = event.key;
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let registry_info = if event.has_ticket_registry {
+        let registry = ctx
+            .accounts
+            .ticket_registry
+            .as_ref()
+            .ok_or(EventError::TicketRegistryRequired)?;
+        require_keys_eq!(
+            registry.load()?.event,
+            event_key,
+            EventError::InvalidEventAccounts
+        );
+        Some(registry.to_account_info())
+    } else {
+        None
+    };
+
+    let mut closed = 0u32;
+    for group in groups {
+        let [ticket_info, mint_info, ownership_info, extra_account_meta_list, token_account_info, rent_recipient] =
+            group
+        else {
+            return err!(EventError::InvalidTicketAccounts);
+        };
+
+        // Returned and already closed tickets no longer have a ticket account
+        if ticket_info.owner != &crate::ID || ticket_info.data_is_empty() {
+            continue;
+        }
+
+        let ticket = Account::<Ticket>::try_from(ticket_info)?;
+        require_keys_eq!(ticket.event, event_key, EventError::InvalidTicketAccounts);
+        require_keys_eq!(
+            mint_info.key(),
+            ticket.mint,
+            EventError::InvalidTicketAccounts
+        );
+
+        if let Some(registry_info) = registry_info.as_ref() {
+            let flags = TicketRegistry::flags(&registry_info.try_borrow_data()?, ticket.id)?;
+            if flags & (TicketRegistry::RETURNED | TicketRegistry::CLOSED) != 0 {
+                continue;
+            }
+        }
+
+        let ownership = Account::<TicketOwnership>::try_from(ownership_info)?;
+        require!(
+            ownership.ticket == ticket.key() && ownership.mint == ticket.mint,
+            EventError::InvalidTicketAccounts
+        );
+        require_keys_eq!(
+            rent_recipient.key(),
+            ticket.rent_recipient(ownership.owner),
+            EventError::RentRecipientMismatch
+        );
+
+        let (extra_account_meta_list_key, _) = Pubkey::find_program_address(
+            &[EXTRA_ACCOUNTS_METAS_SEED, ticket.mint.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            extra_account_meta_list.key(),
+            extra_account_meta_list_key,
+            EventError::InvalidTicketAccounts
+        );
+
+        let ticket_mint = InterfaceAccount::<Mint>::try_from(mint_info)?;
+        let token_account = InterfaceAccount::<TokenAccount>::try_from(token_account_info)?;
+        require!(
+            token_account.mint == ticket.mint && token_account.amount == 1,
+            EventError::InvalidTicketAccounts
+        );
+
+        // The ticket PDA is the permanent delegate and close authority of the mint
+        let ticket_id_bytes = ticket.id.to_be_bytes();
+        let ticket_signer_seeds = &[
+            TICKET_SEED,
+            event_key.as_ref(),
+            &ticket_id_bytes,
+            &[ticket.bump],
+        ];
+        burn_and_close_ticket_mint(
+            &token_program,
+            &ticket_mint.to_account_info(),
+            &token_account.to_account_info(),
+            &ticket.to_account_info(),
+            rent_recipient,
+            &[&ticket_signer_seeds[..]],
+        )?;
+
+        if let Some(registry_info) = registry_info.as_ref() {
+            TicketRegistry::set_flags(
+                &mut registry_info.try_borrow_mut_data()?,
+                ticket.id,
+                TicketRegistry::CLOSED,
+            )?;
+        }
+
+        close_program_account(extra_account_meta_list, rent_recipient)?;
+        ticket.close(rent_recipient.clone())?;
+        ownership.close(rent_recipient.clone())?;
+        closed += 1;
+    }
+
+    msg!("Closed {} expired tickets.", closed);
+
+    Ok(())
+}
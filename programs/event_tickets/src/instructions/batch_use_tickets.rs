@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{EVENT_SEED, TICKET_REGISTRY_SEED},
+    errors::EventError,
+    state::{Event, EventStatus, TicketRegistry},
+};
+
+/// Contextual accounts required to check in many tickets at once.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct BatchUseTickets<'info> {
+    /// The event the tickets belong to.
+    #[account(
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event's ticket registry, where the check-ins are recorded.
+    #[account(
+        mut,
+        seeds = [TICKET_REGISTRY_SEED, event.key().as_ref()],
+        bump = ticket_registry.load()?.bump,
+    )]
+    pub ticket_registry: AccountLoader<'info, TicketRegistry>,
+
+    /// The administrator of the event. Their signature is required to authorize this action.
+    #[account(address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+}
+
+/// Handles the logic for checking in many tickets in one instruction.
+///
+/// Only the ticket registry is updated; the `Ticket` accounts and the NFT metadata
+/// of the checked-in tickets are left untouched.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `ticket_ids` - The IDs of the tickets to mark as used.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn batch_use_tickets_handler(
+    ctx: Context<BatchUseTickets>,
+    _event_id: u64,
+    ticket_ids: Vec<u64>,
+) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let clock = Clock::get()?;
+    event.require_status(clock.unix_timestamp, EventStatus::ACTIVE)?;
+
+    let registry_info = ctx.accounts.ticket_registry.to_account_info();
+    let mut data = registry_info.try_borrow_mut_data()?;
+    for ticket_id in ticket_ids {
+        require!(ticket_id < event.tickets_sold, EventError::TicketNotSold);
+
+        let flags = TicketRegistry::flags(&data, ticket_id)?;
+        require!(
+            flags & TicketRegistry::USED == 0,
+            EventError::TicketAlreadyUsed
+        );
+        require!(
+            flags & (TicketRegistry::RETURNED | TicketRegistry::CLOSED) == 0,
+            EventError::TicketNotActive
+        );
+
+        TicketRegistry::set_flags(&mut data, ticket_id, TicketRegistry::USED)?;
+    }
+
+    Ok(())
+}
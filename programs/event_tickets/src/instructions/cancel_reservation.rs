@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{EVENT_SEED, RESERVATION_SEED, VAULT_SEED},
+    errors::EventError,
+    state::{Event, EventStatus, EventVault, Reservation},
+};
+
+/// Contextual accounts required to cancel a reservation.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct CancelReservation<'info> {
+    /// The event the ticket is reserved for. Its held ticket is released.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event's vault, receiving the forfeited deposit.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+    )]
+    pub event_vault: Account<'info, EventVault>,
+
+    /// The reservation to cancel. The rest of its payments and its rent are returned to the buyer.
+    #[account(
+        mut,
+        seeds = [RESERVATION_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump = reservation.bump,
+        has_one = buyer @ EventError::ReservationMismatch,
+        close = buyer,
+    )]
+    pub reservation: Account<'info, Reservation>,
+
+    /// CHECK: The buyer who made the reservation. Does not need to sign.
+    #[account(mut)]
+    pub buyer: UncheckedAccount<'info>,
+
+    /// Whoever cancels the reservation.
+    pub cranker: Signer<'info>,
+}
+
+/// Handles the logic for cancelling a reservation.
+///
+/// Anyone can cancel a reservation once one of its installments is overdue; the deposit
+/// is then forfeited to the vault and the installments paid so far are returned to the
+/// buyer. Reservations of an event that was cancelled, or that ended before the ticket
+/// was claimed, are cancelled with a full refund.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn cancel_reservation_handler(ctx: Context<CancelReservation>, _event_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let event = &mut ctx.accounts.event;
+    let reservation = &ctx.accounts.reservation;
+
+    let status = event.status_at(clock.unix_timestamp);
+    let missed = reservation
+        .next_installment()
+        .is_some_and(|(_, due)| clock.unix_timestamp > due);
+    if missed && status != EventStatus::Cancelled {
+        **reservation.to_account_info().try_borrow_mut_lamports()? -= reservation.deposit;
+        **ctx
+            .accounts
+            .event_vault
+            .to_account_info()
+            .try_borrow_mut_lamports()? += reservation.deposit;
+    } else {
+        require!(
+            matches!(status, EventStatus::Ended | EventStatus::Cancelled),
+            EventError::ReservationNotCancellable
+        );
+    }
+
+    event.tickets_reserved -= 1;
+
+    Ok(())
+}
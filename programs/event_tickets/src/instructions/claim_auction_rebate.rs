@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::{
+    constants::{EVENT_SEED, TICKET_SEED, VAULT_SEED},
+    errors::EventError,
+    state::{Event, EventVault, PricingMode, Ticket},
+};
+
+/// Contextual accounts required for a ticket holder to claim an auction rebate.
+#[derive(Accounts)]
+#[instruction(event_id: u64, ticket_id: u64)]
+pub struct ClaimAuctionRebate<'info> {
+    /// The auctioned event.
+    #[account(
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event's vault account, which pays out the rebate.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+    )]
+    pub event_vault: Account<'info, EventVault>,
+
+    /// The ticket for which the rebate is claimed.
+    #[account(
+        mut,
+        seeds = [TICKET_SEED, event.key().as_ref(), ticket_id.to_be_bytes().as_ref()],
+        bump = ticket.bump,
+    )]
+    pub ticket: Account<'info, Ticket>,
+
+    /// The current holder of the ticket. Receives the rebate.
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    /// The holder's Associated Token Account holding the ticket NFT.
+    #[account(
+        associated_token::mint = ticket.mint,
+        associated_token::authority = holder,
+        associated_token::token_program = token_program,
+        constraint = holder_ticket_ata.amount == 1 @ EventError::TicketHolderMismatch,
+    )]
+    pub holder_ticket_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Handles the logic for claiming the difference between the price paid for a
+/// ticket and the auction's final clearing price.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `_ticket_id` - The ID of the ticket, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn claim_auction_rebate_handler(
+    ctx: Context<ClaimAuctionRebate>,
+    _event_id: u64,
+    _ticket_id: u64,
+) -> Result<()> {
+    let event = &ctx.accounts.event;
+    let PricingMode::DutchAuction(auction) = &event.pricing else {
+        return err!(EventError::NotAnAuction);
+    };
+
+    let clock = Clock::get()?;
+    require!(
+        auction.rebate_to_clearing_price,
+        EventError::RebateNotEnabled
+    );
+    require!(
+        auction.has_ended(clock.unix_timestamp),
+        EventError::AuctionNotEnded
+    );
+
+    let ticket = &mut ctx.accounts.ticket;
+    let rebate = ticket.price_paid.saturating_sub(event.clearing_price);
+    require!(rebate > 0, EventError::NothingToRebate);
+
+    // Refunds after the rebate only give back the clearing price
+    ticket.price_paid = event.clearing_price;
+
+    **ctx
+        .accounts
+        .event_vault
+        .to_account_info()
+        .try_borrow_mut_lamports()? -= rebate;
+
+    **ctx
+        .accounts
+        .holder
+        .to_account_info()
+        .try_borrow_mut_lamports()? += rebate;
+
+    msg!("Auction rebate of {} lamports claimed", rebate);
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{EVENT_ARCHIVE_SEED, EVENT_SEED, LOTTERY_ENTRY_SEED, LOTTERY_SEED},
+    errors::EventError,
+    state::{Event, EventArchive, EventStatus, Lottery, LotteryEntry},
+};
+
+/// Contextual accounts required to reclaim a lottery deposit.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct ClaimLotteryRefund<'info> {
+    /// The event the lottery belongs to. Omitted once the event has been settled, in favor
+    /// of its archive.
+    #[account(
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Option<Box<Account<'info, Event>>>,
+
+    /// The archive of the event, provided instead of the event once it has been settled.
+    #[account(
+        seeds = [EVENT_ARCHIVE_SEED, lottery.event.as_ref()],
+        bump = event_archive.bump,
+    )]
+    pub event_archive: Option<Account<'info, EventArchive>>,
+
+    /// The lottery the entry was registered for.
+    #[account(
+        seeds = [LOTTERY_SEED, lottery.event.as_ref()],
+        bump = lottery.bump,
+    )]
+    pub lottery: Account<'info, Lottery>,
+
+    /// The entry to close. Its deposit and rent are returned to the entrant.
+    #[account(
+        mut,
+        seeds = [LOTTERY_ENTRY_SEED, lottery.key().as_ref(), entrant.key().as_ref()],
+        bump = lottery_entry.bump,
+        close = entrant,
+    )]
+    pub lottery_entry: Account<'info, LotteryEntry>,
+
+    /// The wallet that registered the entry.
+    #[account(mut)]
+    pub entrant: Signer<'info>,
+}
+
+/// Handles the logic for refunding a lottery deposit.
+///
+/// Losing entries can be refunded as soon as the winners are drawn. Winning entries
+/// that did not buy a ticket can be refunded after the claim window. If the lottery
+/// is never drawn, every entry can be refunded once the event has ended or the draw
+/// window has passed. Every entry
+/// can be refunded once the event is cancelled or settled.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn claim_lottery_refund_handler(
+    ctx: Context<ClaimLotteryRefund>,
+    _event_id: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let lottery = &ctx.accounts.lottery;
+
+    let status = match (
+        ctx.accounts.event.as_deref(),
+        ctx.accounts.event_archive.as_ref(),
+    ) {
+        (Some(event), None) => {
+            require_keys_eq!(event.key(), lottery.event, EventError::InvalidEventAccounts);
+            event.status_at(clock.unix_timestamp)
+        }
+        (None, Some(_)) => EventStatus::Settled,
+        _ => return err!(EventError::InvalidEventAccounts),
+    };
+
+    let refundable = if matches!(status, EventStatus::Cancelled | EventStatus::Settled) {
+        true
+    } else if lottery.drawn {
+        !lottery.is_winner(ctx.accounts.lottery_entry.index)
+            || clock.unix_timestamp >= lottery.config.claim_end
+    } else {
+        status == EventStatus::Ended || lottery.draw_expired(clock.slot)
+    };
+    require!(refundable, EventError::LotteryRefundUnavailable);
+
+    // The entry account, including the deposit, is closed to the entrant by Anchor
+    msg!("Lottery deposit refunded");
+
+    Ok(())
+}
@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::{
+        CLEANUP_BOUNTY, CLEANUP_GRACE_PERIOD, EVENT_ARCHIVE_SEED, EVENT_SEED,
+        EXTRA_ACCOUNTS_METAS_SEED, TICKET_OWNERSHIP_SEED, TICKET_REGISTRY_SEED, TICKET_SEED,
+    },
+    errors::EventError,
+    state::{EndedEvent, Event, EventArchive, Ticket, TicketOwnership, TicketRegistry},
+    utils::{burn_and_close_ticket_mint, close_program_account},
+};
+
+/// Contextual accounts required for anyone to clean up a ticket long after its event ended.
+#[derive(Accounts)]
+#[instruction(event_id: u64, ticket_id: u64)]
+pub struct CleanupExpiredTicket<'info> {
+    /// The event account. Omitted once the event has been settled, in favor of its archive.
+    #[account(
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Option<Box<Account<'info, Event>>>,
+
+    /// The archive of the event, provided instead of the event once it has been settled.
+    #[account(
+        seeds = [EVENT_ARCHIVE_SEED, ticket.event.as_ref()],
+        bump = event_archive.bump,
+    )]
+    pub event_archive: Option<Account<'info, EventArchive>>,
+
+    /// The ticket account to be closed. The bounty is paid from its rent.
+    #[account(
+        mut,
+        seeds = [TICKET_SEED, ticket.event.as_ref(), ticket_id.to_be_bytes().as_ref()],
+        bump = ticket.bump,
+        close = rent_recipient,
+    )]
+    pub ticket: Account<'info, Ticket>,
+
+    /// The mint account of the ticket to be closed.
+    #[account(
+        mut,
+        address = ticket.mint,
+        extensions::permanent_delegate::delegate = ticket,
+        extensions::close_authority::authority = ticket,
+    )]
+    pub ticket_mint: InterfaceAccount<'info, Mint>,
+
+    /// The ownership record of the ticket, closed along with it.
+    #[account(
+        mut,
+        seeds = [TICKET_OWNERSHIP_SEED, ticket_mint.key().as_ref()],
+        bump,
+        close = rent_recipient,
+    )]
+    pub ticket_ownership: Account<'info, TicketOwnership>,
+
+    /// CHECK: The ExtraAccountMetaList of the ticket mint, closed along with it.
+    #[account(
+        mut,
+        seeds = [EXTRA_ACCOUNTS_METAS_SEED, ticket_mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// The token account holding the ticket NFT, emptied by the burn.
+    #[account(
+        mut,
+        token::mint = ticket_mint,
+        token::token_program = token_program,
+    )]
+    pub ticket_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: The wallet receiving the reclaimed rent: the ticket's rent payer, or its
+    /// current holder if the payer opted for that.
+    #[account(
+        mut,
+        address = ticket.rent_recipient(ticket_ownership.owner) @ EventError::RentRecipientMismatch,
+    )]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// Whoever cleans up the ticket. Receives the cleanup bounty.
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    /// The event's ticket registry, where the close is recorded.
+    /// Required when the event tracks ticket statuses in a registry.
+    #[account(
+        mut,
+        seeds = [TICKET_REGISTRY_SEED, ticket.event.as_ref()],
+        bump = ticket_registry.load()?.bump,
+    )]
+    pub ticket_registry: Option<AccountLoader<'info, TicketRegistry>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Handles the logic for cleaning up an expired ticket.
+///
+/// Once the grace period after the event has passed, anyone can burn the ticket NFT and
+/// close the ticket, its mint, its ownership record and its ExtraAccountMetaList.
+/// The cranker receives a fixed bounty from the reclaimed rent, and the rest goes back
+/// to the ticket's rent recipient.
+///
+/// This instruction is permissionless.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `_ticket_id` - The ID of the ticket, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn cleanup_expired_ticket_handler(
+    ctx: Context<CleanupExpiredTicket>,
+    _event_id: u64,
+    _ticket_id: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let event = EndedEvent::resolve(
+        ctx.accounts.event.as_deref(),
+        ctx.accounts.event_archive.as_ref(),
+        clock.unix_timestamp,
+    )?;
+    require_keys_eq!(
+        ctx.accounts.ticket.event,
+        event.key,
+        EventError::InvalidEventAccounts
+    );
+    require!(
+        clock.unix_timestamp > event.end_time + CLEANUP_GRACE_PERIOD,
+        EventError::CleanupGracePeriodActive
+    );
+
+    let event_pubkey = event.key;
+    let ticket_id_bytes = ctx.accounts.ticket.id.to_be_bytes();
+    let ticket_signer_seeds = &[
+        TICKET_SEED,
+        event_pubkey.as_ref(),
+        &ticket_id_bytes,
+        &[ctx.accounts.ticket.bump],
+    ];
+
+    burn_and_close_ticket_mint(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.ticket_mint.to_account_info(),
+        &ctx.accounts.ticket_token_account.to_account_info(),
+        &ctx.accounts.ticket.to_account_info(),
+        &ctx.accounts.rent_recipient.to_account_info(),
+        &[&ticket_signer_seeds[..]],
+    )?;
+
+    close_program_account(
+        &ctx.accounts.extra_account_meta_list.to_account_info(),
+        &ctx.accounts.rent_recipient.to_account_info(),
+    )?;
+
+    // Record the close in the ticket registry
+    if event.has_ticket_registry {
+        let registry_info = ctx
+            .accounts
+            .ticket_registry
+            .as_ref()
+            .ok_or(EventError::TicketRegistryRequired)?
+            .to_account_info();
+        TicketRegistry::set_flags(
+            &mut registry_info.try_borrow_mut_data()?,
+            ctx.accounts.ticket.id,
+            TicketRegistry::CLOSED,
+        )?;
+    }
+
+    // Pay the bounty from the ticket's rent; the rest is returned to the rent recipient
+    // when Anchor closes the ticket and ownership accounts.
+    let bounty = CLEANUP_BOUNTY.min(ctx.accounts.ticket.to_account_info().lamports());
+    **ctx
+        .accounts
+        .ticket
+        .to_account_info()
+        .try_borrow_mut_lamports()? -= bounty;
+    **ctx
+        .accounts
+        .cranker
+        .to_account_info()
+        .try_borrow_mut_lamports()? += bounty;
+
+    Ok(())
+}
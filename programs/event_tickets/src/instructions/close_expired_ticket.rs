@@ -1,48 +1,59 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_2022::{burn_checked, close_account, BurnChecked, CloseAccount},
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{
-    constants::{EVENT_SEED, TICKET_MINT_SEED, TICKET_OWNERSHIP_SEED, TICKET_SEED},
+    constants::{
+        EVENT_ARCHIVE_SEED, EVENT_SEED, EXTRA_ACCOUNTS_METAS_SEED, TICKET_MINT_SEED,
+        TICKET_OWNERSHIP_SEED, TICKET_REGISTRY_SEED, TICKET_SEED,
+    },
     errors::EventError,
-    state::{Event, Ticket, TicketOwnership},
+    state::{EndedEvent, Event, EventArchive, Ticket, TicketOwnership, TicketRegistry},
+    utils::{burn_and_close_ticket_mint, close_program_account},
 };
 
 /// Contextual accounts required for an admin to close an expired ticket account.
 #[derive(Accounts)]
 #[instruction(event_id: u64, ticket_id: u64)]
 pub struct CloseExpiredTicket<'info> {
-    /// The event account.
+    /// The event account. Omitted once the event has been settled, in favor of its archive.
     #[account(
         seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
         bump = event.bump,
     )]
-    pub event: Account<'info, Event>,
+    pub event: Option<Box<Account<'info, Event>>>,
 
-    /// The ticket account to be closed. The rent will be returned to the admin.
+    /// The archive of the event, provided instead of the event once it has been settled.
+    #[account(
+        seeds = [EVENT_ARCHIVE_SEED, ticket.event.as_ref()],
+        bump = event_archive.bump,
+        has_one = admin @ EventError::AuthorityMismatch,
+    )]
+    pub event_archive: Option<Account<'info, EventArchive>>,
+
+    /// The ticket account to be closed. The rent will be returned to the rent recipient.
     #[account(
         mut,
-        seeds = [TICKET_SEED, event.key().as_ref(), ticket_id.to_be_bytes().as_ref()],
+        seeds = [TICKET_SEED, ticket.event.as_ref(), ticket_id.to_be_bytes().as_ref()],
         bump = ticket.bump,
-        close = admin, // The ticket PDA account is closed and rent returned to the admin
+        close = rent_recipient,
     )]
     pub ticket: Account<'info, Ticket>,
 
     /// The mint account of the ticket to be closed.
-    /// This account will be closed and its rent returned to the admin.
+    /// This account will be closed and its rent returned to the rent recipient.
     #[account(
         mut,
         address=ticket.mint,
         extensions::metadata_pointer::authority = ticket,
         extensions::metadata_pointer::metadata_address = ticket_mint,
-        extensions::permanent_delegate::delegate = admin,
-        extensions::close_authority::authority = admin,
+        extensions::permanent_delegate::delegate = ticket,
+        extensions::close_authority::authority = ticket,
         extensions::transfer_hook::authority= ticket,
         extensions::transfer_hook::program_id = crate::ID,
-        seeds = [TICKET_MINT_SEED, event.key().as_ref(), ticket_id.to_be_bytes().as_ref()],
+        seeds = [TICKET_MINT_SEED, ticket.event.as_ref(), ticket_id.to_be_bytes().as_ref()],
         bump
     )]
     pub ticket_mint: InterfaceAccount<'info, Mint>,
@@ -52,25 +63,49 @@ pub struct CloseExpiredTicket<'info> {
         mut,
         seeds = [TICKET_OWNERSHIP_SEED, ticket_mint.key().as_ref()],
         bump,
-        close=admin
+        close = rent_recipient,
     )]
     pub ticket_ownership: Account<'info, TicketOwnership>,
 
-    /// The token account (ATA) holding the ticket NFT.
+    /// CHECK: The ExtraAccountMetaList of the ticket mint, closed along with it.
+    #[account(
+        mut,
+        seeds = [EXTRA_ACCOUNTS_METAS_SEED, ticket_mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// The token account (ATA) of the ticket's current user, holding the ticket NFT.
     /// This account will be closed by the burn instruction.
     #[account(
         mut,
         associated_token::token_program = token_program,
         associated_token::mint = ticket_mint,
-        associated_token::authority = ticket_ownership.owner,
+        associated_token::authority = ticket_ownership.user,
     )]
     pub ticket_ata: InterfaceAccount<'info, TokenAccount>,
 
-    /// The event administrator. Must be a signer and match the admin on the event account.
-    /// This admin is also the permanent_delegate and close_authority for the ticket mint.
-    #[account(mut, address = event.admin @ EventError::AuthorityMismatch)]
+    /// CHECK: The wallet receiving the reclaimed rent: the ticket's rent payer, or its
+    /// current holder if the payer opted for that.
+    #[account(
+        mut,
+        address = ticket.rent_recipient(ticket_ownership.owner) @ EventError::RentRecipientMismatch,
+    )]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// The event administrator. Must be a signer and match the admin of the event,
+    /// which the event's seeds and the archive's `admin` enforce.
     pub admin: Signer<'info>,
 
+    /// The event's ticket registry, where the close is recorded.
+    /// Required when the event tracks ticket statuses in a registry.
+    #[account(
+        mut,
+        seeds = [TICKET_REGISTRY_SEED, ticket.event.as_ref()],
+        bump = ticket_registry.load()?.bump,
+    )]
+    pub ticket_registry: Option<AccountLoader<'info, TicketRegistry>>,
+
     /// The SPL Token 2022 program.
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -80,11 +115,12 @@ pub struct CloseExpiredTicket<'info> {
 /// Handles the logic for closing an expired ticket account.
 ///
 /// This instruction allows the event admin to:
-/// 1. Burn the ticket NFT using its permanent_delegate authority.
-/// 2. Close the ticket's mint account using its close_authority.
-/// 3. Close the ticket's PDA account to reclaim rent.
+/// 1. Burn the ticket NFT, signed by the ticket PDA as the mint's permanent_delegate.
+/// 2. Close the ticket's mint account, signed by the ticket PDA as its close_authority.
+/// 3. Close the ticket's PDA, ownership record and `ExtraAccountMetaList` to reclaim rent.
 ///
-/// This can only be done after the event has ended.
+/// This can only be done after the event has ended, and keeps working against the
+/// event's archive once it has been settled.
 ///
 /// # Arguments
 ///
@@ -101,38 +137,56 @@ pub fn close_expired_ticket_handler(
     _ticket_id: u64,
 ) -> Result<()> {
     let clock = Clock::get()?;
-    require!(
-        ctx.accounts.event.end_time < clock.unix_timestamp,
-        EventError::EventNotEnded
+    let event = EndedEvent::resolve(
+        ctx.accounts.event.as_deref(),
+        ctx.accounts.event_archive.as_ref(),
+        clock.unix_timestamp,
+    )?;
+    require_keys_eq!(
+        ctx.accounts.ticket.event,
+        event.key,
+        EventError::InvalidEventAccounts
     );
 
-    // The `admin` is the permanent_delegate for the mint and can burn the token
-    // from any associated token account without needing the owner's signature.
-    burn_checked(
-        CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            BurnChecked {
-                mint: ctx.accounts.ticket_mint.to_account_info(),
-                from: ctx.accounts.ticket_ata.to_account_info(),
-                authority: ctx.accounts.admin.to_account_info(), // Authority is the Permanent Delegate
-            },
-        ),
-        1,
-        0,
+    let event_pubkey = event.key;
+    let ticket_id_bytes = ctx.accounts.ticket.id.to_be_bytes();
+    let ticket_signer_seeds = &[
+        TICKET_SEED,
+        event_pubkey.as_ref(),
+        &ticket_id_bytes,
+        &[ctx.accounts.ticket.bump],
+    ];
+
+    burn_and_close_ticket_mint(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.ticket_mint.to_account_info(),
+        &ctx.accounts.ticket_ata.to_account_info(),
+        &ctx.accounts.ticket.to_account_info(),
+        &ctx.accounts.rent_recipient.to_account_info(),
+        &[&ticket_signer_seeds[..]],
     )?;
 
-    // Now that the token supply is 0, the `admin` (as the Close Authority) can
-    // close the mint account and reclaim its rent lamports.
-    close_account(CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        CloseAccount {
-            account: ctx.accounts.ticket_mint.to_account_info(),
-            destination: ctx.accounts.admin.to_account_info(), // Send lamports to the admin
-            authority: ctx.accounts.admin.to_account_info(),   // Authority is the Close Authority
-        },
-    ))?;
-
-    // The `ticket` PDA account is closed automatically by Anchor via the `close = admin`
+    close_program_account(
+        &ctx.accounts.extra_account_meta_list.to_account_info(),
+        &ctx.accounts.rent_recipient.to_account_info(),
+    )?;
+
+    // Record the close in the ticket registry
+    if event.has_ticket_registry {
+        let registry_info = ctx
+            .accounts
+            .ticket_registry
+            .as_ref()
+            .ok_or(EventError::TicketRegistryRequired)?
+            .to_account_info();
+        TicketRegistry::set_flags(
+            &mut registry_info.try_borrow_mut_data()?,
+            ctx.accounts.ticket.id,
+            TicketRegistry::CLOSED,
+        )?;
+    }
+
+    // The `ticket` PDA account is closed automatically by Anchor via the `close = rent_recipient`
     // constraint on the account struct. This happens after this handler function returns Ok.
 
     msg!("Expired ticket, mint, and token account closed successfully.");
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{EVENT_SEED, PROMO_CODE_SEED},
+    errors::EventError,
+    state::{Event, PromoCode},
+};
+
+/// Contextual accounts required to retire a promo code.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct ClosePromoCode<'info> {
+    /// The event the promo code applies to.
+    #[account(
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The promo code to close. The rent is returned to the admin.
+    #[account(
+        mut,
+        seeds = [PROMO_CODE_SEED, event.key().as_ref(), promo_code.code_hash.as_ref()],
+        bump = promo_code.bump,
+        close = admin,
+    )]
+    pub promo_code: Account<'info, PromoCode>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(mut, address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+}
+
+/// Handles the logic for closing a promo code, making it unusable immediately.
+///
+/// # Arguments
+///
+/// * `_ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn close_promo_code_handler(_ctx: Context<ClosePromoCode>, _event_id: u64) -> Result<()> {
+    // The promo code account is closed by Anchor via the `close = admin` constraint
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::EVENT_SEED,
+    errors::EventError,
+    state::{DutchAuction, Event, EventStatus, PricingMode},
+};
+
+/// Contextual accounts required to sell an event's tickets by Dutch auction.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct ConfigureAuction<'info> {
+    /// The event whose pricing is being configured.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+}
+
+/// Handles the logic for switching an event to Dutch auction pricing.
+///
+/// The auction price applies to every ticket, including reserved seats with a
+/// section price. It can only be configured before any ticket is sold.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `auction` - The auction configuration.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn configure_auction_handler(
+    ctx: Context<ConfigureAuction>,
+    _event_id: u64,
+    auction: DutchAuction,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    event.require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+    require!(event.tickets_sold == 0, EventError::TicketsAlreadySold);
+    auction.validate(event.end_time)?;
+
+    event.clearing_price = auction.start_price;
+    event.pricing = PricingMode::DutchAuction(auction);
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::EVENT_SEED,
+    errors::EventError,
+    state::{DemandCurve, Event, EventStatus, PricingMode},
+};
+
+/// Contextual accounts required to price an event's tickets by demand.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct ConfigureDemandPricing<'info> {
+    /// The event whose pricing is being configured.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+}
+
+/// Handles the logic for switching an event to demand-based pricing.
+///
+/// The curve is applied on top of the event's `ticket_price`, or the seat section
+/// price for reserved seats. It can only be configured before any ticket is sold.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `curve` - The pricing curve evaluated against the tickets in circulation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn configure_demand_pricing_handler(
+    ctx: Context<ConfigureDemandPricing>,
+    _event_id: u64,
+    curve: DemandCurve,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    event.require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+    require!(event.tickets_sold == 0, EventError::TicketsAlreadySold);
+    curve.validate()?;
+
+    event.pricing = PricingMode::Demand(curve);
+
+    Ok(())
+}
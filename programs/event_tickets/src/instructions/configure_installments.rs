@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{EVENT_SEED, MAX_INSTALLMENTS},
+    errors::EventError,
+    state::{Event, EventStatus, InstallmentPlan},
+};
+
+/// Contextual accounts required to let buyers pay an event's tickets in installments.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct ConfigureInstallments<'info> {
+    /// The event whose installment plan is being configured.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+}
+
+/// Handles the logic for setting or clearing the installment plan of an event.
+///
+/// The plan only applies to reservations made afterwards; existing reservations keep
+/// the schedule they were made with.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `plan` - The deposit and installment schedule, or `None` to stop taking reservations.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn configure_installments_handler(
+    ctx: Context<ConfigureInstallments>,
+    _event_id: u64,
+    plan: Option<InstallmentPlan>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    event.require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+
+    if let Some(plan) = &plan {
+        require!(
+            plan.deposit_bps > 0
+                && plan.deposit_bps < 10_000
+                && plan.installments > 0
+                && plan.installments <= MAX_INSTALLMENTS
+                && plan.interval > 0,
+            EventError::InvalidInstallmentPlan
+        );
+    }
+
+    event.installment_plan = plan;
+
+    Ok(())
+}
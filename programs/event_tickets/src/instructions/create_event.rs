@@ -1,12 +1,23 @@
 use crate::{
     constants::{DISCRIMINATOR_LENGHT, EVENT_COUNTER_SEED, EVENT_SEED, VAULT_SEED},
     errors::EventError,
-    state::{Event, EventCounter, EventVault},
+    state::{Event, EventCounter, EventDetails, EventStatus, EventVault, PricingMode},
 };
 use anchor_lang::prelude::*;
 
 /// Contextual accounts required to create a new event.
 #[derive(Accounts)]
+#[instruction(
+    name: String,
+    symbol: String,
+    description: String,
+    metadata_uri: String,
+    start_time: i64,
+    end_time: i64,
+    ticket_price: u64,
+    total_tickets: u64,
+    details: EventDetails,
+)]
 pub struct CreateEvent<'info> {
     /// The event counter account, specific to the admin.
     /// It is initialized if it does not exist.
@@ -21,10 +32,11 @@ pub struct CreateEvent<'info> {
 
     /// The new event account, initialized by this instruction.
     /// The PDA is derived from the admin's key and the current event ID.
+    /// It is sized for the given strings, see `Event::space_for`.
     #[account(
         init,
         payer = admin,
-        space = DISCRIMINATOR_LENGHT + Event::INIT_SPACE,
+        space = DISCRIMINATOR_LENGHT + Event::space_for(&name, &symbol, &description, &metadata_uri, None, &details),
         seeds = [EVENT_SEED, admin.key().as_ref(), event_counter.next_event_id.to_be_bytes().as_ref()],
         bump,
     )]
@@ -62,6 +74,9 @@ pub struct CreateEvent<'info> {
 /// * `end_time` - The Unix timestamp for when the event ends.
 /// * `ticket_price` - The price of one ticket in lamports.
 /// * `total_tickets` - The total number of tickets available for sale.
+/// * `details` - The venue, category and age rating of the event.
+/// * `draft` - Whether the event is created as a draft, which can be configured privately
+///   and must be published with `publish_event` before tickets go on sale.
 ///
 /// # Returns
 ///
@@ -76,6 +91,8 @@ pub fn create_event_handler(
     end_time: i64,
     ticket_price: u64,
     total_tickets: u64,
+    details: EventDetails,
+    draft: bool,
 ) -> Result<()> {
     // Validation
     require!(name.len() >= 3, EventError::NameTooShort);
@@ -86,6 +103,7 @@ pub fn create_event_handler(
     require!(end_time > start_time, EventError::InvalidEventTime);
     require!(total_tickets > 0, EventError::InvalidTicketCount);
     require!(ticket_price > 0, EventError::InvalidTicketPrice);
+    details.validate()?;
 
     // Initialize Event Counter (if new)
     let event_counter = &mut ctx.accounts.event_counter;
@@ -102,11 +120,26 @@ pub fn create_event_handler(
     event.symbol = symbol;
     event.description = description;
     event.metadata_uri = metadata_uri;
+    event.uri_template = None;
+    event.details = details;
+    event.attestation = None;
+    event.gatekeeper = None;
     event.start_time = start_time;
     event.end_time = end_time;
+    event.status = if draft {
+        EventStatus::Draft
+    } else {
+        EventStatus::OnSale
+    };
     event.ticket_price = ticket_price;
     event.total_tickets = total_tickets;
     event.tickets_sold = 0;
+    event.tickets_reserved = 0;
+    event.installment_plan = None;
+    event.loyalty = None;
+    event.lite_registry = None;
+    event.has_ticket_registry = false;
+    event.pricing = PricingMode::Fixed;
     event.bump = ctx.bumps.event;
 
     // Increment Event Counter
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::EVENT_SEED,
+    errors::EventError,
+    state::{Event, EventStatus, LiteRegistry},
+};
+
+/// Contextual accounts required to switch an event to lite, non-NFT tickets.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct CreateLiteRegistry<'info> {
+    /// The event that will issue lite tickets.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The registry account. It must be allocated and assigned to this program beforehand,
+    /// in the same transaction, with room for at least `total_tickets` entries.
+    #[account(zero)]
+    pub lite_registry: AccountLoader<'info, LiteRegistry>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+}
+
+/// Handles the logic for creating the lite ticket registry of an event.
+///
+/// Once a registry exists, tickets are recorded in it instead of being minted as NFTs.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn create_lite_registry_handler(
+    ctx: Context<CreateLiteRegistry>,
+    _event_id: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    event.require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+    require!(event.tickets_sold == 0, EventError::TicketsAlreadySold);
+    require!(
+        !event.has_seat_map && !event.has_lottery && !event.has_waitlist,
+        EventError::LiteModeUnsupported
+    );
+
+    let capacity =
+        LiteRegistry::capacity_for(ctx.accounts.lite_registry.to_account_info().data_len());
+    require!(
+        capacity >= event.total_tickets,
+        EventError::LiteRegistryTooSmall
+    );
+
+    let mut registry = ctx.accounts.lite_registry.load_init()?;
+    registry.event = event.key();
+    registry.capacity = capacity;
+
+    event.lite_registry = Some(ctx.accounts.lite_registry.key());
+
+    Ok(())
+}
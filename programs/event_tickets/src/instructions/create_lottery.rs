@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_LENGHT, EVENT_SEED, LOTTERY_SEED},
+    errors::EventError,
+    state::{Event, EventStatus, Lottery, LotteryConfig},
+};
+
+/// Contextual accounts required to set up a ticket lottery for an event.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct CreateLottery<'info> {
+    /// The event whose tickets will be allocated by lottery.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The lottery account, initialized by this instruction.
+    #[account(
+        init,
+        payer = admin,
+        space = DISCRIMINATOR_LENGHT + Lottery::INIT_SPACE,
+        seeds = [LOTTERY_SEED, event.key().as_ref()],
+        bump,
+    )]
+    pub lottery: Account<'info, Lottery>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(mut, address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for creating the lottery of an event.
+///
+/// Until `claim_end`, only lottery winners can buy tickets for the event. Entries close at
+/// the earlier of `entry_end` and `draw_slot`, whose hash later seeds the draw.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `config` - The entry windows, number of winners, deposit, draw slot and optional commitment.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn create_lottery_handler(
+    ctx: Context<CreateLottery>,
+    _event_id: u64,
+    config: LotteryConfig,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    event.require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+    require!(event.tickets_sold == 0, EventError::TicketsAlreadySold);
+    config.validate(event, Clock::get()?.slot)?;
+
+    let lottery = &mut ctx.accounts.lottery;
+    lottery.event = event.key();
+    lottery.config = config;
+    lottery.entries = 0;
+    lottery.drawn = false;
+    lottery.seed = [0; 32];
+    lottery.bump = ctx.bumps.lottery;
+
+    event.has_lottery = true;
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_LENGHT, EVENT_SEED, MAX_SEAT_SECTIONS, PROMO_CODE_SEED},
+    errors::EventError,
+    state::{Discount, Event, EventStatus, PromoCode, PromoCodeConfig},
+};
+
+/// Contextual accounts required to create a promo code for an event.
+#[derive(Accounts)]
+#[instruction(event_id: u64, code_hash: [u8; 32])]
+pub struct CreatePromoCode<'info> {
+    /// The event the promo code applies to.
+    #[account(
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The promo code account, keyed by the hash of the code.
+    #[account(
+        init,
+        payer = admin,
+        space = DISCRIMINATOR_LENGHT + PromoCode::INIT_SPACE,
+        seeds = [PROMO_CODE_SEED, event.key().as_ref(), code_hash.as_ref()],
+        bump,
+    )]
+    pub promo_code: Account<'info, PromoCode>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(mut, address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for creating a promo code.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `code_hash` - The SHA-256 hash of the code, e.g. `sha256("EARLY20")`.
+/// * `config` - The discount, usage cap, validity window and section restriction.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn create_promo_code_handler(
+    ctx: Context<CreatePromoCode>,
+    _event_id: u64,
+    code_hash: [u8; 32],
+    config: PromoCodeConfig,
+) -> Result<()> {
+    ctx.accounts
+        .event
+        .require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+    match config.discount {
+        Discount::Percentage { bps } => {
+            require!(bps > 0 && bps <= 10_000, EventError::InvalidDiscount)
+        }
+        Discount::Fixed { amount } => require!(amount > 0, EventError::InvalidDiscount),
+    }
+    require!(
+        config.max_redemptions > 0 && config.valid_until > config.valid_from,
+        EventError::InvalidPromoCodeConfig
+    );
+    require!(
+        config.allowed_sections.len() <= MAX_SEAT_SECTIONS as usize,
+        EventError::InvalidSectionCount
+    );
+
+    let promo_code = &mut ctx.accounts.promo_code;
+    promo_code.event = ctx.accounts.event.key();
+    promo_code.code_hash = code_hash;
+    promo_code.config = config;
+    promo_code.redemptions = 0;
+    promo_code.bump = ctx.bumps.promo_code;
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_LENGHT, EVENT_SEED, SEAT_MAP_SEED},
+    errors::EventError,
+    state::{Event, EventStatus, SeatMap, SeatSection},
+};
+
+/// Contextual accounts required to configure reserved seating for an event.
+#[derive(Accounts)]
+#[instruction(event_id: u64, sections: Vec<SeatSection>)]
+pub struct CreateSeatMap<'info> {
+    /// The event that will use reserved seating.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The seat map account, sized for the given sections and their seats.
+    #[account(
+        init,
+        payer = admin,
+        space = DISCRIMINATOR_LENGHT + SeatMap::space(&sections, event.total_tickets as u32),
+        seeds = [SEAT_MAP_SEED, event.key().as_ref()],
+        bump,
+    )]
+    pub seat_map: Account<'info, SeatMap>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(mut, address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for creating the seat map of an event.
+///
+/// Once a seat map exists, every ticket must be bought for a specific seat.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `sections` - The sections of the venue, each with its rows, seats and optional price.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn create_seat_map_handler(
+    ctx: Context<CreateSeatMap>,
+    _event_id: u64,
+    sections: Vec<SeatSection>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    event.require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+    require!(event.tickets_sold == 0, EventError::TicketsAlreadySold);
+    let total_seats = SeatMap::validate_sections(&sections, event.total_tickets)?;
+
+    let seat_map = &mut ctx.accounts.seat_map;
+    seat_map.event = event.key();
+    seat_map.total_seats = total_seats as u32;
+    seat_map.sold = vec![0; (total_seats as usize).div_ceil(8)];
+    seat_map.sections = sections;
+    seat_map.bump = ctx.bumps.seat_map;
+
+    event.has_seat_map = true;
+
+    msg!("Seat map created with {} seats", total_seats);
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+
+use crate::{
+    constants::{EVENT_SEED, TICKET_REGISTRY_SEED},
+    errors::EventError,
+    state::{Event, EventStatus, TicketRegistry},
+};
+
+/// Contextual accounts required to track the status of an event's tickets in a registry.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct CreateTicketRegistry<'info> {
+    /// The event whose tickets are tracked.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The ticket registry, sized for the event's tickets as far as a single allocation allows.
+    /// It grows as more tickets are sold.
+    #[account(
+        init,
+        payer = admin,
+        space = TicketRegistry::space_for(event.total_tickets).min(MAX_PERMITTED_DATA_INCREASE),
+        seeds = [TICKET_REGISTRY_SEED, event.key().as_ref()],
+        bump,
+    )]
+    pub ticket_registry: AccountLoader<'info, TicketRegistry>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(mut, address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for creating the ticket registry of an event.
+///
+/// Once a registry exists, check-ins, returns and closes are recorded in it and
+/// it must be passed to every instruction that changes a ticket's status.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn create_ticket_registry_handler(
+    ctx: Context<CreateTicketRegistry>,
+    _event_id: u64,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+
+    event.require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+    require!(event.tickets_sold == 0, EventError::TicketsAlreadySold);
+    require!(event.lite_registry.is_none(), EventError::LiteModeEnabled);
+
+    let mut registry = ctx.accounts.ticket_registry.load_init()?;
+    registry.event = event.key();
+    registry.bump = ctx.bumps.ticket_registry;
+
+    event.has_ticket_registry = true;
+
+    Ok(())
+}
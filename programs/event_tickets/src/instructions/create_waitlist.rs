@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_LENGHT, EVENT_SEED, WAITLIST_SEED},
+    errors::EventError,
+    state::{Event, EventStatus, Waitlist},
+};
+
+/// Contextual accounts required to open a waitlist for an event.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct CreateWaitlist<'info> {
+    /// The event whose returned tickets will be offered to the waitlist.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The waitlist account, initialized by this instruction.
+    #[account(
+        init,
+        payer = admin,
+        space = DISCRIMINATOR_LENGHT + Waitlist::INIT_SPACE,
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump,
+    )]
+    pub waitlist: Account<'info, Waitlist>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(mut, address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for creating the waitlist of an event.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `claim_window` - How long, in seconds, an entry has to claim an offered ticket.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn create_waitlist_handler(
+    ctx: Context<CreateWaitlist>,
+    _event_id: u64,
+    claim_window: i64,
+) -> Result<()> {
+    ctx.accounts
+        .event
+        .require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+    require!(claim_window > 0, EventError::InvalidClaimWindow);
+
+    let waitlist = &mut ctx.accounts.waitlist;
+    waitlist.event = ctx.accounts.event.key();
+    waitlist.claim_window = claim_window;
+    waitlist.bump = ctx.bumps.waitlist;
+
+    ctx.accounts.event.has_waitlist = true;
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{
+        EVENT_SEED, LOTTERY_SEED, SEAT_MAP_SEED, TICKET_REGISTRY_SEED, VAULT_SEED, WAITLIST_SEED,
+    },
+    errors::EventError,
+    state::{
+        Event, EventStatus, EventVault, LiteRegistry, Lottery, SeatMap, TicketRegistry, Waitlist,
+    },
+};
+
+/// Contextual accounts required to delete a draft event and reclaim its rent.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct DeleteDraftEvent<'info> {
+    /// The draft event to be deleted.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+        constraint = event.status == EventStatus::Draft @ EventError::EventNotDraft,
+        close = admin,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event vault to be closed.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+        close = admin,
+    )]
+    pub event_vault: Account<'info, EventVault>,
+
+    /// The event administrator, who receives the reclaimed rent.
+    #[account(mut, address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+
+    /// The event's seat map, closed along with it if it was created.
+    #[account(
+        mut,
+        seeds = [SEAT_MAP_SEED, event.key().as_ref()],
+        bump = seat_map.bump,
+        close = admin,
+    )]
+    pub seat_map: Option<Account<'info, SeatMap>>,
+
+    /// The event's lottery, closed along with it if it was created.
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED, event.key().as_ref()],
+        bump = lottery.bump,
+        close = admin,
+    )]
+    pub lottery: Option<Account<'info, Lottery>>,
+
+    /// The event's waitlist, closed along with it if it was created.
+    #[account(
+        mut,
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump = waitlist.bump,
+        close = admin,
+    )]
+    pub waitlist: Option<Account<'info, Waitlist>>,
+
+    /// The event's ticket registry, closed along with it if it was created.
+    #[account(
+        mut,
+        seeds = [TICKET_REGISTRY_SEED, event.key().as_ref()],
+        bump = ticket_registry.load()?.bump,
+        close = admin,
+    )]
+    pub ticket_registry: Option<AccountLoader<'info, TicketRegistry>>,
+
+    /// The event's lite ticket registry, closed along with it if it was created.
+    #[account(
+        mut,
+        constraint = event.lite_registry == Some(lite_registry.key()) @ EventError::InvalidLiteTicket,
+        close = admin,
+    )]
+    pub lite_registry: Option<AccountLoader<'info, LiteRegistry>>,
+}
+
+/// Handles the logic for deleting a draft event.
+///
+/// Drafts cannot sell tickets or take lottery and waitlist entries, so only accounts
+/// paid for by the organizer are left: the event, its vault and whichever of the seat
+/// map, lottery, waitlist and registries were created. Promo codes are closed with
+/// `close_promo_code`.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn delete_draft_event_handler(ctx: Context<DeleteDraftEvent>, _event_id: u64) -> Result<()> {
+    // The accounts are closed to the admin by Anchor
+    msg!("Draft event {} deleted", ctx.accounts.event.id);
+
+    Ok(())
+}
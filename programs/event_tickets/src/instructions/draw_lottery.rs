@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{hash::hashv, sysvar::slot_hashes};
+
+use crate::{
+    constants::{EVENT_SEED, LOTTERY_SEED},
+    errors::EventError,
+    state::{Event, EventStatus, Lottery},
+};
+
+/// Contextual accounts required to draw the winners of a lottery.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct DrawLottery<'info> {
+    /// The event the lottery belongs to.
+    #[account(
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The lottery to draw.
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED, event.key().as_ref()],
+        bump = lottery.bump,
+    )]
+    pub lottery: Account<'info, Lottery>,
+
+    /// Whoever draws the lottery. Anyone can, as the result only depends on the draw slot
+    /// and the committed secret.
+    pub cranker: Signer<'info>,
+
+    /// CHECK: The SlotHashes sysvar, read directly to avoid deserializing all entries.
+    #[account(address = slot_hashes::ID)]
+    pub recent_slot_hashes: UncheckedAccount<'info>,
+}
+
+/// Handles the logic for drawing the winners of a lottery.
+///
+/// The randomness seed is the hash of the lottery's draw slot, committed to when the
+/// lottery was created, mixed with the admin's secret when the lottery was created with
+/// a commitment. Anyone can draw the lottery once the draw slot has passed. The secret
+/// must be revealed within `LOTTERY_REVEAL_WINDOW_SLOTS`, after which anyone can draw
+/// from the slot hash alone, so the admin cannot void a result they dislike by keeping
+/// the secret. The draw must happen within `LOTTERY_DRAW_WINDOW_SLOTS`, while the slot
+/// hash is available. Winners are the first `winners` entries in the random order given
+/// by the seed, see `Lottery::is_winner`.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `secret` - The preimage of the lottery commitment, required while the reveal window is open.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn draw_lottery_handler(
+    ctx: Context<DrawLottery>,
+    _event_id: u64,
+    secret: Option<[u8; 32]>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts
+        .event
+        .require_status(clock.unix_timestamp, EventStatus::ACTIVE)?;
+    let lottery = &mut ctx.accounts.lottery;
+
+    require!(!lottery.drawn, EventError::LotteryAlreadyDrawn);
+    require!(
+        clock.unix_timestamp >= lottery.config.entry_end,
+        EventError::LotteryEntryOpen
+    );
+    require!(lottery.entries > 0, EventError::NoLotteryEntries);
+    require!(
+        clock.slot > lottery.config.draw_slot,
+        EventError::LotteryDrawSlotNotReached
+    );
+    require!(
+        !lottery.draw_expired(clock.slot),
+        EventError::LotteryDrawExpired
+    );
+
+    let secret = match lottery.config.commitment {
+        Some(commitment) if lottery.reveal_required(clock.slot) => {
+            let secret = secret.ok_or(EventError::InvalidLotterySecret)?;
+            require!(
+                hashv(&[&secret]).to_bytes() == commitment,
+                EventError::InvalidLotterySecret
+            );
+            secret
+        }
+        // A secret withheld past the reveal window no longer counts
+        _ => [0; 32],
+    };
+
+    // SlotHashes layout: u64 length, followed by (slot: u64, hash: [u8; 32]) entries, newest
+    // first. The draw uses the first block at or after the draw slot, in case it was skipped.
+    let draw_slot = lottery.config.draw_slot;
+    let slot_hashes_data = ctx.accounts.recent_slot_hashes.try_borrow_data()?;
+    let count = u64::from_le_bytes(slot_hashes_data[..8].try_into().unwrap()) as usize;
+    let draw_slot_hash = slot_hashes_data[8..]
+        .chunks_exact(40)
+        .take(count)
+        .map(|entry| {
+            (
+                u64::from_le_bytes(entry[..8].try_into().unwrap()),
+                &entry[8..],
+            )
+        })
+        .take_while(|(slot, _)| *slot >= draw_slot)
+        .last()
+        .map(|(_, hash)| hash)
+        .ok_or(EventError::LotteryDrawExpired)?;
+
+    lottery.seed = hashv(&[&secret, draw_slot_hash]).to_bytes();
+    lottery.drawn = true;
+
+    msg!(
+        "Lottery drawn: {} winner(s) among {} entries",
+        lottery.config.winners.min(lottery.entries),
+        lottery.entries
+    );
+
+    Ok(())
+}
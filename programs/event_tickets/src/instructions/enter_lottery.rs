@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{
+    constants::{DISCRIMINATOR_LENGHT, EVENT_SEED, LOTTERY_ENTRY_SEED, LOTTERY_SEED},
+    errors::EventError,
+    state::{Event, EventStatus, Lottery, LotteryEntry},
+};
+
+/// Contextual accounts required to register for an event's ticket lottery.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct EnterLottery<'info> {
+    /// The event the lottery belongs to.
+    #[account(
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The lottery being entered.
+    #[account(
+        mut,
+        seeds = [LOTTERY_SEED, event.key().as_ref()],
+        bump = lottery.bump,
+    )]
+    pub lottery: Account<'info, Lottery>,
+
+    /// The entry account, one per wallet. It also holds the entry deposit.
+    #[account(
+        init,
+        payer = entrant,
+        space = DISCRIMINATOR_LENGHT + LotteryEntry::INIT_SPACE,
+        seeds = [LOTTERY_ENTRY_SEED, lottery.key().as_ref(), entrant.key().as_ref()],
+        bump,
+    )]
+    pub lottery_entry: Account<'info, LotteryEntry>,
+
+    /// The fan registering for the lottery. Pays the deposit and the entry rent.
+    #[account(mut)]
+    pub entrant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for entering a lottery.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn enter_lottery_handler(ctx: Context<EnterLottery>, _event_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts
+        .event
+        .require_status(clock.unix_timestamp, EventStatus::ACTIVE)?;
+    let lottery = &mut ctx.accounts.lottery;
+
+    require!(
+        clock.unix_timestamp >= lottery.config.entry_start
+            && clock.unix_timestamp < lottery.config.entry_end
+            && clock.slot < lottery.config.draw_slot,
+        EventError::LotteryEntryClosed
+    );
+
+    // The deposit is held by the entry account until it is refunded or the ticket is bought
+    if lottery.config.deposit > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.entrant.to_account_info(),
+                    to: ctx.accounts.lottery_entry.to_account_info(),
+                },
+            ),
+            lottery.config.deposit,
+        )?;
+    }
+
+    let entry = &mut ctx.accounts.lottery_entry;
+    entry.lottery = lottery.key();
+    entry.entrant = ctx.accounts.entrant.key();
+    entry.index = lottery.entries;
+    entry.bump = ctx.bumps.lottery_entry;
+
+    lottery.entries = lottery
+        .entries
+        .checked_add(1)
+        .ok_or(EventError::NumericOverflow)?;
+
+    msg!("Lottery entry #{} registered", entry.index);
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{EVENT_SEED, WAITLIST_ENTRY_SEED, WAITLIST_SEED},
+    errors::EventError,
+    state::{Event, Waitlist, WaitlistEntry, WaitlistStatus},
+};
+
+/// Contextual accounts required to expire an unclaimed waitlist offer.
+#[derive(Accounts)]
+#[instruction(event_id: u64, position: u64)]
+pub struct ExpireWaitlistOffer<'info> {
+    /// The event the waitlist belongs to.
+    #[account(
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event's waitlist.
+    #[account(
+        mut,
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump = waitlist.bump,
+    )]
+    pub waitlist: Account<'info, Waitlist>,
+
+    /// The entry whose offer has expired.
+    #[account(
+        mut,
+        seeds = [WAITLIST_ENTRY_SEED, waitlist.key().as_ref(), position.to_be_bytes().as_ref()],
+        bump = waitlist_entry.bump,
+    )]
+    pub waitlist_entry: Account<'info, WaitlistEntry>,
+
+    /// Anyone can crank the waitlist.
+    pub cranker: Signer<'info>,
+}
+
+/// Handles the logic for expiring an unclaimed offer.
+///
+/// The reserved ticket goes back to the queue so it can be offered to the next
+/// entry, or is released to general sale if nobody else is waiting.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `_position` - The position of the entry, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn expire_waitlist_offer_handler(
+    ctx: Context<ExpireWaitlistOffer>,
+    _event_id: u64,
+    _position: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let waitlist = &mut ctx.accounts.waitlist;
+    let entry = &mut ctx.accounts.waitlist_entry;
+
+    require!(
+        entry.status == WaitlistStatus::Offered,
+        EventError::InvalidWaitlistStatus
+    );
+    require!(
+        clock.unix_timestamp > entry.offer_expires_at,
+        EventError::WaitlistOfferActive
+    );
+
+    entry.status = WaitlistStatus::Expired;
+    waitlist.active_offers -= 1;
+    if waitlist.open_slots < waitlist.queued() {
+        waitlist.open_slots += 1;
+    }
+
+    msg!("Waitlist offer for position {} expired", entry.position);
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{
+    constants::{DISCRIMINATOR_LENGHT, EVENT_SEED, WAITLIST_ENTRY_SEED, WAITLIST_SEED},
+    errors::EventError,
+    state::{Event, EventStatus, Waitlist, WaitlistEntry, WaitlistStatus},
+};
+
+/// Contextual accounts required to join an event's waitlist.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct JoinWaitlist<'info> {
+    /// The event the waitlist belongs to.
+    #[account(
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The waitlist being joined.
+    #[account(
+        mut,
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump = waitlist.bump,
+    )]
+    pub waitlist: Account<'info, Waitlist>,
+
+    /// The entry account at the end of the queue. It also holds the deposit.
+    #[account(
+        init,
+        payer = buyer,
+        space = DISCRIMINATOR_LENGHT + WaitlistEntry::INIT_SPACE,
+        seeds = [WAITLIST_ENTRY_SEED, waitlist.key().as_ref(), waitlist.next_position.to_be_bytes().as_ref()],
+        bump,
+    )]
+    pub waitlist_entry: Account<'info, WaitlistEntry>,
+
+    /// The buyer joining the waitlist. Pays the deposit and the entry rent.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for joining a waitlist.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `deposit` - The prepaid amount in lamports; it must cover the ticket price when the offer is claimed.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn join_waitlist_handler(
+    ctx: Context<JoinWaitlist>,
+    _event_id: u64,
+    deposit: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts
+        .event
+        .require_status(clock.unix_timestamp, EventStatus::ACTIVE)?;
+    require!(deposit > 0, EventError::InvalidWaitlistDeposit);
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.waitlist_entry.to_account_info(),
+            },
+        ),
+        deposit,
+    )?;
+
+    let waitlist = &mut ctx.accounts.waitlist;
+    let entry = &mut ctx.accounts.waitlist_entry;
+    entry.waitlist = waitlist.key();
+    entry.buyer = ctx.accounts.buyer.key();
+    entry.position = waitlist.next_position;
+    entry.deposit = deposit;
+    entry.status = WaitlistStatus::Queued;
+    entry.bump = ctx.bumps.waitlist_entry;
+
+    waitlist.next_position = waitlist
+        .next_position
+        .checked_add(1)
+        .ok_or(EventError::NumericOverflow)?;
+
+    msg!("Joined the waitlist at position {}", entry.position);
+
+    Ok(())
+}
@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants::{EVENT_SEED, TICKET_OWNERSHIP_SEED, TICKET_SEED},
+    errors::EventError,
+    state::{Event, EventStatus, Ticket, TicketOwnership},
+    utils::move_ticket_nft,
+};
+
+/// Contextual accounts required for an owner to lend their ticket.
+#[derive(Accounts)]
+#[instruction(event_id: u64, ticket_id: u64)]
+pub struct LendTicket<'info> {
+    /// The event account to which the ticket belongs.
+    #[account(
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The ticket account being lent.
+    #[account(
+        seeds = [TICKET_SEED, event.key().as_ref(), ticket_id.to_be_bytes().as_ref()],
+        bump = ticket.bump,
+    )]
+    pub ticket: Account<'info, Ticket>,
+
+    /// The ticket NFT mint. The ticket PDA moves the NFT as its permanent delegate and mint authority.
+    #[account(
+        mut,
+        address = ticket.mint,
+        extensions::permanent_delegate::delegate = ticket,
+    )]
+    pub ticket_mint: InterfaceAccount<'info, Mint>,
+
+    /// The ownership record of the ticket, recording the loan.
+    #[account(
+        mut,
+        seeds = [TICKET_OWNERSHIP_SEED, ticket_mint.key().as_ref()],
+        bump,
+        constraint = ticket_ownership.owner == lender.key() @ EventError::Unauthorized,
+    )]
+    pub ticket_ownership: Account<'info, TicketOwnership>,
+
+    /// The owner lending the ticket. Must be a signer and pays for the borrower's token account.
+    #[account(mut)]
+    pub lender: Signer<'info>,
+
+    /// The lender's Associated Token Account holding the ticket NFT.
+    #[account(
+        mut,
+        associated_token::mint = ticket_mint,
+        associated_token::authority = lender,
+        associated_token::token_program = token_program,
+    )]
+    pub lender_ticket_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: The wallet borrowing the ticket. It does not need to sign.
+    pub borrower: UncheckedAccount<'info>,
+
+    /// The borrower's Associated Token Account, receiving the ticket NFT for the loan.
+    #[account(
+        init_if_needed,
+        payer = lender,
+        associated_token::mint = ticket_mint,
+        associated_token::authority = borrower,
+        associated_token::token_program = token_program,
+    )]
+    pub borrower_ticket_ata: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for lending a ticket until a deadline.
+///
+/// The ticket NFT is moved to the borrower, who can check in with it, while the lender
+/// remains its owner in the `TicketOwnership` record. The ticket cannot be transferred
+/// or returned during the loan, and is brought back to the lender with `reclaim_ticket`.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `_ticket_id` - The ID of the ticket, used for PDA validation.
+/// * `lent_until` - The Unix timestamp after which the lender can reclaim the ticket.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn lend_ticket_handler(
+    ctx: Context<LendTicket>,
+    _event_id: u64,
+    _ticket_id: u64,
+    lent_until: i64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts
+        .event
+        .require_status(clock.unix_timestamp, EventStatus::ACTIVE)?;
+    require!(!ctx.accounts.ticket.used, EventError::TicketAlreadyUsed);
+    require!(
+        ctx.accounts.ticket_ownership.lent_until.is_none(),
+        EventError::TicketOnLoan
+    );
+    require!(
+        lent_until > clock.unix_timestamp && lent_until <= ctx.accounts.ticket.valid_until,
+        EventError::InvalidLoanDeadline
+    );
+
+    let event_pubkey = ctx.accounts.event.key();
+    let ticket_id_bytes = ctx.accounts.ticket.id.to_be_bytes();
+    let ticket_signer_seeds = &[
+        TICKET_SEED,
+        event_pubkey.as_ref(),
+        &ticket_id_bytes,
+        &[ctx.accounts.ticket.bump],
+    ];
+
+    move_ticket_nft(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.ticket_mint.to_account_info(),
+        &ctx.accounts.lender_ticket_ata.to_account_info(),
+        &ctx.accounts.borrower_ticket_ata.to_account_info(),
+        &ctx.accounts.ticket.to_account_info(),
+        &[&ticket_signer_seeds[..]],
+    )?;
+
+    let ownership = &mut ctx.accounts.ticket_ownership;
+    ownership.user = ctx.accounts.borrower.key();
+    ownership.lent_until = Some(lent_until);
+
+    Ok(())
+}
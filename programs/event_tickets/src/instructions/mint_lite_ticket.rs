@@ -0,0 +1,196 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{
+    constants::{DISCRIMINATOR_LENGHT, EVENT_SEED, GATEKEEPER_PASS_SEED, LOYALTY_SEED, VAULT_SEED},
+    errors::EventError,
+    state::{
+        Event, EventStatus, EventVault, GatekeeperPass, LiteRegistry, LiteTicket, LoyaltyRecord,
+        PricingMode,
+    },
+    utils::{resize_account, verify_purchase_approval},
+};
+
+/// Contextual accounts required to buy a lite ticket.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct MintLiteTicket<'info> {
+    /// The event account for which the ticket is being bought.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event's vault account, where the ticket payment will be sent.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump
+    )]
+    pub event_vault: Account<'info, EventVault>,
+
+    /// The event's lite ticket registry, where the ticket is recorded.
+    #[account(
+        mut,
+        address = event.lite_registry.ok_or(EventError::LiteModeNotEnabled)? @ EventError::LiteModeNotEnabled,
+    )]
+    pub lite_registry: AccountLoader<'info, LiteRegistry>,
+
+    /// The buyer of the ticket. Must be a signer and pays for the ticket, and for the room
+    /// needed in the registry when a returned ticket is resold.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: The wallet receiving the ticket. May be the buyer itself or any other wallet.
+    pub recipient: UncheckedAccount<'info>,
+
+    /// CHECK: The instructions sysvar, used to find the buyer's attestation and gatekeeper token.
+    /// Required when the event only sells tickets to attested buyers, or when its gatekeeper
+    /// approves the purchase with a token.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// The event's gatekeeper, co-signing the purchase. Required when the event has a
+    /// gatekeeper, unless the transaction carries a gatekeeper token.
+    pub gatekeeper: Option<Signer<'info>>,
+
+    /// The buyer's gatekeeper pass, recording the tokens used so that each buys once.
+    /// Required when the purchase is approved with a gatekeeper token.
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = DISCRIMINATOR_LENGHT + GatekeeperPass::INIT_SPACE,
+        seeds = [GATEKEEPER_PASS_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub gatekeeper_pass: Option<Box<Account<'info, GatekeeperPass>>>,
+
+    /// The recipient's attendance record with the event's admin. Required for the
+    /// recipient to get the event's loyalty perks.
+    #[account(
+        seeds = [LOYALTY_SEED, event.admin.as_ref(), recipient.key().as_ref()],
+        bump = loyalty.bump,
+    )]
+    pub loyalty: Option<Box<Account<'info, LoyaltyRecord>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for buying a lite ticket.
+///
+/// The ticket is recorded in the event's registry under the next ticket id,
+/// without creating any account for the buyer.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `max_price` - The highest price the buyer accepts, protecting against price changes.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn mint_lite_ticket_handler(
+    ctx: Context<MintLiteTicket>,
+    _event_id: u64,
+    max_price: Option<u64>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let clock = Clock::get()?;
+
+    event.require_status(clock.unix_timestamp, EventStatus::SELLING)?;
+    require!(
+        !event.has_seat_map && !event.has_lottery && !event.has_waitlist,
+        EventError::LiteModeUnsupported
+    );
+
+    let token = verify_purchase_approval(
+        event,
+        ctx.accounts.instructions_sysvar.as_deref(),
+        ctx.accounts.gatekeeper.as_ref().map(|signer| signer.key()),
+        &ctx.accounts.buyer.key(),
+        &ctx.accounts.recipient.key(),
+        &clock,
+    )?;
+
+    // Each gatekeeper token approves a single purchase
+    if let Some(token) = token {
+        match (
+            ctx.accounts.gatekeeper_pass.as_mut(),
+            ctx.bumps.gatekeeper_pass,
+        ) {
+            (Some(pass), Some(bump)) => pass.consume(&token, bump)?,
+            _ => return err!(EventError::GatekeeperPassRequired),
+        }
+    }
+
+    require!(
+        event.tickets_sold < event.total_tickets + event.tickets_returned,
+        EventError::EventSoldOut
+    );
+
+    let current_price = event.current_price(event.ticket_price, clock.unix_timestamp)?;
+    let ticket_price = event.loyalty_price(
+        ctx.accounts.loyalty.as_deref().map(|record| &**record),
+        current_price,
+        clock.unix_timestamp,
+    )?;
+    if let Some(max_price) = max_price {
+        require!(ticket_price <= max_price, EventError::PriceAboveMaximum);
+    }
+    // The clearing price follows the auction, not the recipient's loyalty discount,
+    // as every earlier buyer is rebated down to it
+    if let PricingMode::DutchAuction(_) = event.pricing {
+        event.clearing_price = current_price;
+    }
+
+    // Payment Transfer
+    require!(
+        **ctx.accounts.buyer.to_account_info().lamports.borrow() >= ticket_price,
+        EventError::InsufficientFunds
+    );
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.event_vault.to_account_info(),
+            },
+        ),
+        ticket_price,
+    )?;
+
+    // Returned tickets are resold under new ids, past the `total_tickets` entries the
+    // registry was created with, so the buyer makes room for the entry when it is full
+    let registry_info = ctx.accounts.lite_registry.to_account_info();
+    if LiteRegistry::capacity_for(registry_info.data_len()) <= event.tickets_sold {
+        resize_account(
+            &registry_info,
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            LiteRegistry::space_for(event.tickets_sold + 1),
+        )?;
+        ctx.accounts.lite_registry.load_mut()?.capacity = event.tickets_sold + 1;
+    }
+
+    // Record the ticket in the registry
+    LiteRegistry::write_entry(
+        &mut registry_info.try_borrow_mut_data()?,
+        event.tickets_sold,
+        &LiteTicket {
+            owner: ctx.accounts.recipient.key(),
+            price_paid: ticket_price,
+            status: LiteTicket::VALID,
+        },
+    )?;
+
+    event.tickets_sold = event
+        .tickets_sold
+        .checked_add(1)
+        .ok_or(EventError::NumericOverflow)?;
+
+    Ok(())
+}
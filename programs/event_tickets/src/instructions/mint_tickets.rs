@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
 use anchor_lang::solana_program::rent::{
     DEFAULT_EXEMPTION_THRESHOLD, DEFAULT_LAMPORTS_PER_BYTE_YEAR,
 };
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
 use anchor_lang::system_program::{transfer, Transfer};
 use anchor_spl::token_2022::{mint_to, MintTo};
 use anchor_spl::{
@@ -19,11 +21,20 @@ use spl_type_length_value::variable_len_pack::VariableLenPack;
 
 use crate::{
     constants::{
-        DISCRIMINATOR_LENGHT, EVENT_SEED, EXTRA_ACCOUNTS_METAS_SEED, TICKET_MINT_SEED,
-        TICKET_OWNERSHIP_SEED, TICKET_SEED, VAULT_SEED,
+        DISCRIMINATOR_LENGHT, EVENT_SEED, EXTRA_ACCOUNTS_METAS_SEED, GATEKEEPER_PASS_SEED,
+        LOTTERY_SEED, LOYALTY_SEED, METADATA_CATEGORY_KEY, METADATA_EVENT_ID_KEY,
+        METADATA_GEOHASH_KEY, METADATA_MIN_AGE_KEY, METADATA_SEAT_KEY, METADATA_STATUS_KEY,
+        METADATA_UTC_OFFSET_KEY, METADATA_VENUE_KEY, PROMO_CODE_SEED, RESERVATION_SEED,
+        SEAT_MAP_SEED, TICKET_MINT_SEED, TICKET_OWNERSHIP_SEED, TICKET_REGISTRY_GROWTH,
+        TICKET_REGISTRY_SEED, TICKET_SEED, TICKET_STATUS_VALID, VAULT_SEED, WAITLIST_SEED,
     },
     errors::EventError,
-    state::{Event, EventVault, Ticket, TicketOwnership},
+    state::{
+        Event, EventStatus, EventVault, GatekeeperPass, Lottery, LotteryEntry, LoyaltyRecord,
+        PricingMode, PromoCode, Reservation, SeatMap, SeatSelection, Ticket, TicketOwnership,
+        TicketRegistry, Waitlist, WaitlistEntry, WaitlistStatus,
+    },
+    utils::{resize_account, update_ticket_metadata, verify_purchase_approval},
 };
 /// Contextual accounts required to mint a ticket NFT for an event.
 #[derive(Accounts)]
@@ -47,10 +58,17 @@ pub struct MintTicket<'info> {
     )]
     pub event_vault: Account<'info, EventVault>,
 
-    /// The buyer of the ticket. Must be a signer.
+    /// The buyer of the ticket. Must be a signer and pays for the ticket and the rent.
     #[account(mut)]
     pub buyer: Signer<'info>,
 
+    /// CHECK: The wallet receiving the ticket NFT. May be the buyer itself or, for gifts,
+    /// any other wallet; it only needs to sign to use a winning lottery entry. Lottery, waitlist
+    /// and loyalty entitlements are checked against the recipient, who also receives the
+    /// leftover entry deposits.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
     /// The PDA account that will store the ticket's metadata.
     #[account(
         init,
@@ -71,8 +89,8 @@ pub struct MintTicket<'info> {
         mint::token_program = token_program,
         extensions::metadata_pointer::authority = ticket,
         extensions::metadata_pointer::metadata_address = ticket_mint,
-        extensions::permanent_delegate::delegate = event.admin,
-        extensions::close_authority::authority = event.admin,
+        extensions::permanent_delegate::delegate = ticket,
+        extensions::close_authority::authority = ticket,
         extensions::transfer_hook::authority= ticket,
         extensions::transfer_hook::program_id = crate::ID,
         seeds = [TICKET_MINT_SEED, event.key().as_ref(), event.tickets_sold.to_be_bytes().as_ref()],
@@ -102,16 +120,116 @@ pub struct MintTicket<'info> {
     )]
     pub ticket_ownership: Account<'info, TicketOwnership>,
 
-    /// The buyer's Associated Token Account (ATA) to receive the ticket NFT.
+    /// The recipient's Associated Token Account (ATA) to receive the ticket NFT.
     /// It will be created if it does not exist.
     #[account(
         init_if_needed,
         payer = buyer,
         associated_token::token_program = token_program,
         associated_token::mint = ticket_mint,
-        associated_token::authority = buyer,
+        associated_token::authority = recipient,
+    )]
+    pub recipient_ticket_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// The event's seat map. Required when the event has reserved seating.
+    #[account(
+        mut,
+        seeds = [SEAT_MAP_SEED, event.key().as_ref()],
+        bump = seat_map.bump,
+    )]
+    pub seat_map: Option<Account<'info, SeatMap>>,
+
+    /// The event's lottery. Required when the event allocates tickets by lottery.
+    #[account(
+        seeds = [LOTTERY_SEED, event.key().as_ref()],
+        bump = lottery.bump,
+    )]
+    pub lottery: Option<Box<Account<'info, Lottery>>>,
+
+    /// The recipient's winning lottery entry. It is closed and its deposit returned to the recipient,
+    /// who must sign so that nobody else can use up the entry.
+    #[account(
+        mut,
+        constraint = lottery_entry.entrant == recipient.key() @ EventError::LotteryEntryMismatch,
+        constraint = recipient.is_signer @ EventError::LotteryEntrantNotSigner,
+        close = recipient,
+    )]
+    pub lottery_entry: Option<Box<Account<'info, LotteryEntry>>>,
+
+    /// The event's waitlist. Required when the event has a waitlist.
+    #[account(
+        mut,
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump = waitlist.bump,
+    )]
+    pub waitlist: Option<Box<Account<'info, Waitlist>>>,
+
+    /// The recipient's waitlist entry holding an offer. The ticket is paid from its deposit
+    /// when the entrant is the buyer, and by the buyer otherwise. The entry is closed with
+    /// the remaining deposit returned to the recipient.
+    #[account(
+        mut,
+        constraint = waitlist_entry.buyer == recipient.key() @ EventError::WaitlistEntryMismatch,
+        close = recipient,
+    )]
+    pub waitlist_entry: Option<Box<Account<'info, WaitlistEntry>>>,
+
+    /// The promo code being redeemed. Required when a code is provided.
+    #[account(
+        mut,
+        seeds = [PROMO_CODE_SEED, event.key().as_ref(), promo_code.code_hash.as_ref()],
+        bump = promo_code.bump,
+    )]
+    pub promo_code: Option<Box<Account<'info, PromoCode>>>,
+
+    /// The recipient's attendance record with the event's admin. Required for the
+    /// recipient to get the event's loyalty perks.
+    #[account(
+        seeds = [LOYALTY_SEED, event.admin.as_ref(), recipient.key().as_ref()],
+        bump = loyalty.bump,
     )]
-    pub buyer_ticket_ata: InterfaceAccount<'info, TokenAccount>,
+    pub loyalty: Option<Box<Account<'info, LoyaltyRecord>>>,
+
+    /// The buyer's fully paid installment reservation. The ticket is paid from it, and it is
+    /// closed with its rent returned to the buyer.
+    #[account(
+        mut,
+        seeds = [RESERVATION_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump = reservation.bump,
+        close = buyer,
+    )]
+    pub reservation: Option<Box<Account<'info, Reservation>>>,
+
+    /// The event's ticket registry, grown by the buyer when it has no room for the new ticket.
+    /// Required when the event tracks ticket statuses in a registry.
+    #[account(
+        mut,
+        seeds = [TICKET_REGISTRY_SEED, event.key().as_ref()],
+        bump = ticket_registry.load()?.bump,
+    )]
+    pub ticket_registry: Option<AccountLoader<'info, TicketRegistry>>,
+
+    /// CHECK: The instructions sysvar, used to find the buyer's attestation and gatekeeper token.
+    /// Required when the event only sells tickets to attested buyers, or when its gatekeeper
+    /// approves the purchase with a token.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// The event's gatekeeper, co-signing the purchase. Required when the event has a
+    /// gatekeeper, unless the transaction carries a gatekeeper token.
+    pub gatekeeper: Option<Signer<'info>>,
+
+    /// The buyer's gatekeeper pass, recording the tokens used so that each buys once.
+    /// Required when the purchase is approved with a gatekeeper token.
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = DISCRIMINATOR_LENGHT + GatekeeperPass::INIT_SPACE,
+        seeds = [GATEKEEPER_PASS_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub gatekeeper_pass: Option<Box<Account<'info, GatekeeperPass>>>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -123,38 +241,234 @@ pub struct MintTicket<'info> {
 ///
 /// * `ctx` - The context containing all necessary accounts.
 /// * `_event_id` - The ID of the event, used for PDA validation in the account constraints.
+/// * `seat` - The seat to buy, required if and only if the event has reserved seating.
+/// * `max_price` - The highest price the buyer accepts, protecting against price changes.
+/// * `promo_code` - The promo code to redeem for a discount, if any.
 ///
 /// # Returns
 ///
 /// An empty `Result` indicating success or failure.
-pub fn mint_ticket_handler(ctx: Context<MintTicket>, _event_id: u64) -> Result<()> {
+pub fn mint_ticket_handler(
+    ctx: Context<MintTicket>,
+    _event_id: u64,
+    seat: Option<SeatSelection>,
+    max_price: Option<u64>,
+    promo_code: Option<String>,
+) -> Result<()> {
     let event = &mut ctx.accounts.event;
     let clock = Clock::get()?;
 
+    event.require_status(clock.unix_timestamp, EventStatus::SELLING)?;
+    require!(event.lite_registry.is_none(), EventError::LiteModeEnabled);
+
+    let token = verify_purchase_approval(
+        event,
+        ctx.accounts.instructions_sysvar.as_deref(),
+        ctx.accounts.gatekeeper.as_ref().map(|signer| signer.key()),
+        &ctx.accounts.buyer.key(),
+        &ctx.accounts.recipient.key(),
+        &clock,
+    )?;
+
+    // Each gatekeeper token approves a single purchase
+    if let Some(token) = token {
+        match (
+            ctx.accounts.gatekeeper_pass.as_mut(),
+            ctx.bumps.gatekeeper_pass,
+        ) {
+            (Some(pass), Some(bump)) => pass.consume(&token, bump)?,
+            _ => return err!(EventError::GatekeeperPassRequired),
+        }
+    }
+
+    // Tickets reserved for the waitlist can only be bought by the entry they were offered to
+    let mut reserved = 0;
+    if event.has_waitlist {
+        let waitlist = ctx
+            .accounts
+            .waitlist
+            .as_mut()
+            .ok_or(EventError::WaitlistRequired)?;
+        if let Some(entry) = ctx.accounts.waitlist_entry.as_ref() {
+            require!(
+                entry.waitlist == waitlist.key(),
+                EventError::WaitlistEntryMismatch
+            );
+            require!(
+                entry.status == WaitlistStatus::Offered,
+                EventError::InvalidWaitlistStatus
+            );
+            require!(
+                clock.unix_timestamp <= entry.offer_expires_at,
+                EventError::WaitlistOfferExpired
+            );
+            waitlist.active_offers -= 1;
+        } else {
+            reserved = waitlist.reserved();
+        }
+    } else {
+        require!(
+            ctx.accounts.waitlist_entry.is_none(),
+            EventError::WaitlistEntryMismatch
+        );
+    }
+
+    // A paid-up reservation releases the ticket it holds for its buyer
+    if let Some(reservation) = ctx.accounts.reservation.as_ref() {
+        require!(reservation.is_paid(), EventError::ReservationNotPaid);
+        event.tickets_reserved -= 1;
+    }
+
     require!(
-        event.end_time > clock.unix_timestamp,
-        EventError::EventEnded
-    );
-    require!(
-        event.tickets_sold < event.total_tickets + event.tickets_returned,
+        event.tickets_sold + reserved + event.tickets_reserved
+            < event.total_tickets + event.tickets_returned,
         EventError::EventSoldOut
     );
-    require!(
-        **ctx.accounts.buyer.to_account_info().lamports.borrow() >= event.ticket_price,
-        EventError::InsufficientFunds
-    );
+
+    // Only lottery winners can buy tickets until the claim window closes
+    if event.has_lottery {
+        let lottery = ctx
+            .accounts
+            .lottery
+            .as_ref()
+            .ok_or(EventError::LotteryRequired)?;
+        // A lottery that was not drawn in time is void, and tickets go on general sale
+        if clock.unix_timestamp < lottery.config.claim_end && !lottery.draw_expired(clock.slot) {
+            require!(lottery.drawn, EventError::LotteryNotDrawn);
+            let entry = ctx
+                .accounts
+                .lottery_entry
+                .as_ref()
+                .ok_or(EventError::NotALotteryWinner)?;
+            require!(
+                entry.lottery == lottery.key(),
+                EventError::LotteryEntryMismatch
+            );
+            require!(
+                lottery.is_winner(entry.index),
+                EventError::NotALotteryWinner
+            );
+        }
+    }
+
+    // Reserve the selected seat, if the event uses reserved seating
+    let mut base_price = event.ticket_price;
+    let mut seat_label = String::new();
+    let mut seat_position = None;
+    if event.has_seat_map {
+        let (Some(seat_map), Some(selection)) = (ctx.accounts.seat_map.as_mut(), seat.as_ref())
+        else {
+            return err!(EventError::SeatRequired);
+        };
+        let index = seat_map.seat_index(selection)?;
+        require!(!seat_map.is_sold(index), EventError::SeatAlreadySold);
+        seat_map.set_sold(index, true);
+        base_price = seat_map.seat_price(selection, event.ticket_price);
+        seat_label = seat_map.seat_label(selection);
+        seat_position = Some((selection.section, index));
+    } else {
+        require!(seat.is_none(), EventError::SeatingNotEnabled);
+    }
+
+    // Reserved tickets keep the price locked when they were reserved, others get the
+    // recipient's loyalty discount
+    let current_price = event.current_price(base_price, clock.unix_timestamp)?;
+    let mut ticket_price = match ctx.accounts.reservation.as_ref() {
+        Some(reservation) => reservation.price,
+        None => event.loyalty_price(
+            ctx.accounts.loyalty.as_deref().map(|record| &**record),
+            current_price,
+            clock.unix_timestamp,
+        )?,
+    };
+
+    // Apply the promo code discount, if a code was provided
+    if let Some(code) = promo_code {
+        require!(
+            ctx.accounts.reservation.is_none(),
+            EventError::PromoCodeNotApplicable
+        );
+        let promo = ctx
+            .accounts
+            .promo_code
+            .as_mut()
+            .ok_or(EventError::PromoCodeRequired)?;
+        require!(
+            promo.code_hash == hash(code.as_bytes()).to_bytes(),
+            EventError::InvalidPromoCode
+        );
+        require!(
+            clock.unix_timestamp >= promo.config.valid_from
+                && clock.unix_timestamp <= promo.config.valid_until,
+            EventError::PromoCodeInactive
+        );
+        require!(
+            promo.redemptions < promo.config.max_redemptions,
+            EventError::PromoCodeExhausted
+        );
+        if !promo.config.allowed_sections.is_empty() {
+            let section = seat.as_ref().map(|selection| selection.section);
+            require!(
+                section.is_some_and(|section| promo.config.allowed_sections.contains(&section)),
+                EventError::PromoCodeNotApplicable
+            );
+        }
+        promo.redemptions += 1;
+        ticket_price = promo.config.discount.apply(ticket_price);
+    }
+
+    if let Some(max_price) = max_price {
+        require!(ticket_price <= max_price, EventError::PriceAboveMaximum);
+    }
+    // The clearing price follows the auction, not the discounts granted to this buyer or
+    // the price locked by a reservation, as every earlier buyer is rebated down to it
+    if matches!(event.pricing, PricingMode::DutchAuction(_)) && ctx.accounts.reservation.is_none() {
+        event.clearing_price = current_price;
+    }
 
     // Payment Transfer
-    transfer(
-        CpiContext::new(
-            ctx.accounts.system_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.buyer.to_account_info(),
-                to: ctx.accounts.event_vault.to_account_info(),
-            },
-        ),
-        event.ticket_price,
-    )?;
+    if let Some(entry) = ctx
+        .accounts
+        .waitlist_entry
+        .as_ref()
+        .filter(|entry| entry.buyer == ctx.accounts.buyer.key())
+    {
+        // Entrants claiming for themselves pay from the prepaid deposit; the rest is returned
+        // when the entry closes, and gifts leave the whole deposit to the entrant
+        require!(
+            entry.deposit >= ticket_price,
+            EventError::WaitlistDepositTooLow
+        );
+        **entry.to_account_info().try_borrow_mut_lamports()? -= ticket_price;
+        **ctx
+            .accounts
+            .event_vault
+            .to_account_info()
+            .try_borrow_mut_lamports()? += ticket_price;
+    } else if let Some(reservation) = ctx.accounts.reservation.as_ref() {
+        // Reserved tickets are paid from the installments held by the reservation
+        **reservation.to_account_info().try_borrow_mut_lamports()? -= ticket_price;
+        **ctx
+            .accounts
+            .event_vault
+            .to_account_info()
+            .try_borrow_mut_lamports()? += ticket_price;
+    } else {
+        require!(
+            **ctx.accounts.buyer.to_account_info().lamports.borrow() >= ticket_price,
+            EventError::InsufficientFunds
+        );
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.buyer.to_account_info(),
+                    to: ctx.accounts.event_vault.to_account_info(),
+                },
+            ),
+            ticket_price,
+        )?;
+    }
 
     let extra_account_metas = MintTicket::extra_account_metas()?;
 
@@ -176,10 +490,35 @@ pub fn mint_ticket_handler(ctx: Context<MintTicket>, _event_id: u64) -> Result<(
     ];
     let signer = &[&ticket_signer_seeds[..]];
 
+    let mut additional_metadata = vec![
+        (METADATA_EVENT_ID_KEY, event.id.to_string()),
+        (METADATA_STATUS_KEY, TICKET_STATUS_VALID.to_string()),
+        (METADATA_VENUE_KEY, event.details.venue.clone()),
+        (METADATA_GEOHASH_KEY, event.details.geohash.clone()),
+        (
+            METADATA_CATEGORY_KEY,
+            event.details.category.as_str().to_string(),
+        ),
+        (METADATA_MIN_AGE_KEY, event.details.min_age.to_string()),
+        (
+            METADATA_UTC_OFFSET_KEY,
+            event.details.utc_offset_minutes.to_string(),
+        ),
+    ];
+    if !seat_label.is_empty() {
+        additional_metadata.push((METADATA_SEAT_KEY, seat_label.clone()));
+    }
+
+    let uri = event.ticket_uri(event.tickets_sold, seat_position)?;
+
     let token_metadata = TokenMetadata {
         name: nft_name.clone(),
         symbol: event.symbol.clone(),
-        uri: event.metadata_uri.clone(),
+        uri: uri.clone(),
+        additional_metadata: additional_metadata
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect(),
         ..Default::default()
     };
     let data_len = 4 + token_metadata.get_packed_len()?;
@@ -211,7 +550,17 @@ pub fn mint_ticket_handler(ctx: Context<MintTicket>, _event_id: u64) -> Result<(
         ),
         nft_name,
         event.symbol.clone(),
-        event.metadata_uri.clone(),
+        uri,
+    )?;
+
+    update_ticket_metadata(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.ticket_mint.to_account_info(),
+        &ctx.accounts.ticket.to_account_info(),
+        &ctx.accounts.buyer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        signer,
+        &additional_metadata,
     )?;
 
     // NFT Minting
@@ -221,7 +570,7 @@ pub fn mint_ticket_handler(ctx: Context<MintTicket>, _event_id: u64) -> Result<(
             ctx.accounts.token_program.to_account_info(),
             MintTo {
                 mint: ctx.accounts.ticket_mint.to_account_info(),
-                to: ctx.accounts.buyer_ticket_ata.to_account_info(),
+                to: ctx.accounts.recipient_ticket_ata.to_account_info(),
                 authority: ctx.accounts.ticket.to_account_info(),
             },
             signer,
@@ -236,14 +585,39 @@ pub fn mint_ticket_handler(ctx: Context<MintTicket>, _event_id: u64) -> Result<(
     ticket.mint = ctx.accounts.ticket_mint.key();
     ticket.valid_until = event.end_time;
     ticket.used = false;
+    ticket.price_paid = ticket_price;
+    ticket.payer = ctx.accounts.buyer.key();
+    ticket.refund_rent_to_holder = false;
+    ticket.seat = seat;
+    ticket.seat_label = seat_label;
     ticket.bump = ctx.bumps.ticket;
 
     // Initialize Ticket Ownership Account
     let ownership = &mut ctx.accounts.ticket_ownership;
-    ownership.owner = ctx.accounts.buyer.key();
+    ownership.owner = ctx.accounts.recipient.key();
+    ownership.user = ctx.accounts.recipient.key();
+    ownership.lent_until = None;
     ownership.ticket = ticket.key();
     ownership.mint = ctx.accounts.ticket_mint.key();
 
+    // Make room for the new ticket in the ticket registry
+    if event.has_ticket_registry {
+        let registry_info = ctx
+            .accounts
+            .ticket_registry
+            .as_ref()
+            .ok_or(EventError::TicketRegistryRequired)?
+            .to_account_info();
+        if TicketRegistry::capacity_for(registry_info.data_len()) <= ticket.id {
+            resize_account(
+                &registry_info,
+                &ctx.accounts.buyer.to_account_info(),
+                &ctx.accounts.system_program.to_account_info(),
+                TicketRegistry::space_for(ticket.id + TICKET_REGISTRY_GROWTH),
+            )?;
+        }
+    }
+
     // Update Event State
     event.tickets_sold = event
         .tickets_sold
@@ -1,15 +1,97 @@
+pub mod advance_event_status;
+pub mod batch_close_expired_tickets;
+pub mod batch_use_tickets;
+pub mod cancel_reservation;
+pub mod claim_auction_rebate;
+pub mod claim_lottery_refund;
+pub mod cleanup_expired_ticket;
 pub mod close_expired_ticket;
+pub mod close_promo_code;
+pub mod configure_auction;
+pub mod configure_demand_pricing;
+pub mod configure_installments;
 pub mod create_event;
+pub mod create_lite_registry;
+pub mod create_lottery;
+pub mod create_promo_code;
+pub mod create_seat_map;
+pub mod create_ticket_registry;
+pub mod create_waitlist;
+pub mod delete_draft_event;
+pub mod draw_lottery;
+pub mod enter_lottery;
+pub mod expire_waitlist_offer;
+pub mod join_waitlist;
+pub mod lend_ticket;
+pub mod mint_lite_ticket;
 pub mod mint_tickets;
+pub mod offer_waitlist_slot;
+pub mod pay_installment;
+pub mod publish_event;
+pub mod reclaim_ticket;
+pub mod refund_waitlist_entry;
+pub mod reserve_ticket;
+pub mod return_lite_ticket;
 pub mod return_ticket;
+pub mod set_attestation_requirement;
+pub mod set_gatekeeper;
+pub mod set_loyalty_program;
+pub mod set_rent_refund_to_holder;
+pub mod set_uri_template;
+pub mod transfer_lite_ticket;
 pub mod trasfer_hook;
+pub mod update_event_details;
+pub mod update_event_info;
+pub mod update_event_status;
+pub mod use_lite_ticket;
 pub mod use_ticket;
 pub mod withdraw_funds;
 
+pub use advance_event_status::*;
+pub use batch_close_expired_tickets::*;
+pub use batch_use_tickets::*;
+pub use cancel_reservation::*;
+pub use claim_auction_rebate::*;
+pub use claim_lottery_refund::*;
+pub use cleanup_expired_ticket::*;
 pub use close_expired_ticket::*;
+pub use close_promo_code::*;
+pub use configure_auction::*;
+pub use configure_demand_pricing::*;
+pub use configure_installments::*;
 pub use create_event::*;
+pub use create_lite_registry::*;
+pub use create_lottery::*;
+pub use create_promo_code::*;
+pub use create_seat_map::*;
+pub use create_ticket_registry::*;
+pub use create_waitlist::*;
+pub use delete_draft_event::*;
+pub use draw_lottery::*;
+pub use enter_lottery::*;
+pub use expire_waitlist_offer::*;
+pub use join_waitlist::*;
+pub use lend_ticket::*;
+pub use mint_lite_ticket::*;
 pub use mint_tickets::*;
+pub use offer_waitlist_slot::*;
+pub use pay_installment::*;
+pub use publish_event::*;
+pub use reclaim_ticket::*;
+pub use refund_waitlist_entry::*;
+pub use reserve_ticket::*;
+pub use return_lite_ticket::*;
 pub use return_ticket::*;
+pub use set_attestation_requirement::*;
+pub use set_gatekeeper::*;
+pub use set_loyalty_program::*;
+pub use set_rent_refund_to_holder::*;
+pub use set_uri_template::*;
+pub use transfer_lite_ticket::*;
 pub use trasfer_hook::*;
+pub use update_event_details::*;
+pub use update_event_info::*;
+pub use update_event_status::*;
+pub use use_lite_ticket::*;
 pub use use_ticket::*;
 pub use withdraw_funds::*;
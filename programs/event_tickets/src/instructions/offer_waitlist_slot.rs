@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{EVENT_SEED, WAITLIST_ENTRY_SEED, WAITLIST_SEED},
+    errors::EventError,
+    state::{Event, Waitlist, WaitlistEntry, WaitlistStatus},
+};
+
+/// Contextual accounts required to offer a reserved ticket to the head of the waitlist.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct OfferWaitlistSlot<'info> {
+    /// The event the waitlist belongs to.
+    #[account(
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event's waitlist.
+    #[account(
+        mut,
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump = waitlist.bump,
+    )]
+    pub waitlist: Account<'info, Waitlist>,
+
+    /// The entry at the head of the queue, which receives the offer.
+    #[account(
+        mut,
+        seeds = [WAITLIST_ENTRY_SEED, waitlist.key().as_ref(), waitlist.next_offer.to_be_bytes().as_ref()],
+        bump = waitlist_entry.bump,
+    )]
+    pub waitlist_entry: Account<'info, WaitlistEntry>,
+
+    /// Anyone can crank the waitlist.
+    pub cranker: Signer<'info>,
+}
+
+/// Handles the logic for offering a reserved ticket to the next entry in line.
+///
+/// This instruction is permissionless so that offers keep moving even when the
+/// organizer is not online.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn offer_waitlist_slot_handler(ctx: Context<OfferWaitlistSlot>, _event_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let waitlist = &mut ctx.accounts.waitlist;
+    let entry = &mut ctx.accounts.waitlist_entry;
+
+    require!(waitlist.open_slots > 0, EventError::NoOpenWaitlistSlot);
+    require!(
+        entry.status == WaitlistStatus::Queued,
+        EventError::InvalidWaitlistStatus
+    );
+
+    entry.status = WaitlistStatus::Offered;
+    entry.offer_expires_at = clock
+        .unix_timestamp
+        .checked_add(waitlist.claim_window)
+        .ok_or(EventError::NumericOverflow)?;
+
+    waitlist.next_offer += 1;
+    waitlist.open_slots -= 1;
+    waitlist.active_offers += 1;
+
+    msg!(
+        "Ticket offered to waitlist position {} until {}",
+        entry.position,
+        entry.offer_expires_at
+    );
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{
+    constants::{EVENT_SEED, RESERVATION_SEED},
+    errors::EventError,
+    state::{Event, EventStatus, Reservation},
+};
+
+/// Contextual accounts required to pay the next installment of a reservation.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct PayInstallment<'info> {
+    /// The event the ticket is reserved for.
+    #[account(
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The reservation being paid. It holds the payments until the ticket is claimed.
+    #[account(
+        mut,
+        seeds = [RESERVATION_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump = reservation.bump,
+    )]
+    pub reservation: Account<'info, Reservation>,
+
+    /// The buyer who made the reservation.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for paying the next installment of a reservation.
+///
+/// Installments must be paid by their due date. Once the last one is paid, the buyer
+/// claims the ticket NFT with `mint_ticket`, passing the reservation, which can be done
+/// in the same transaction.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn pay_installment_handler(ctx: Context<PayInstallment>, _event_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts
+        .event
+        .require_status(clock.unix_timestamp, EventStatus::ACTIVE)?;
+
+    let (amount, due) = ctx
+        .accounts
+        .reservation
+        .next_installment()
+        .ok_or(EventError::ReservationAlreadyPaid)?;
+    require!(clock.unix_timestamp <= due, EventError::InstallmentOverdue);
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.reservation.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let reservation = &mut ctx.accounts.reservation;
+    reservation.paid += amount;
+
+    msg!(
+        "Installment paid: {} of {} lamports",
+        reservation.paid,
+        reservation.price
+    );
+
+    Ok(())
+}
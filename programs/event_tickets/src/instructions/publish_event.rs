@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{EVENT_SEED, LOTTERY_SEED, SEAT_MAP_SEED, WAITLIST_SEED},
+    errors::EventError,
+    state::{Event, EventStatus, Lottery, SeatMap, Waitlist},
+};
+
+/// Contextual accounts required to publish a draft event.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct PublishEvent<'info> {
+    /// The draft event to publish.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+
+    /// The event's lottery. Required when the event is sold by lottery.
+    #[account(
+        seeds = [LOTTERY_SEED, event.key().as_ref()],
+        bump = lottery.bump,
+    )]
+    pub lottery: Option<Account<'info, Lottery>>,
+
+    /// The event's seat map. Required when the event sells reserved seating.
+    #[account(
+        seeds = [SEAT_MAP_SEED, event.key().as_ref()],
+        bump = seat_map.bump,
+    )]
+    pub seat_map: Option<Account<'info, SeatMap>>,
+
+    /// The event's waitlist. Required when the event has a waitlist.
+    #[account(
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump = waitlist.bump,
+    )]
+    pub waitlist: Option<Account<'info, Waitlist>>,
+}
+
+/// Handles the logic for publishing a draft event.
+///
+/// Runs the same validation as `create_event` and the configuration instructions
+/// against the full setup of the draft: the event and its pricing mode, and its seat
+/// map, lottery and waitlist when it has them. It also checks what may have gone stale
+/// while the event was a draft: the event, its auction and its lottery entry window
+/// must not be over yet, and the lottery's draw slot must not have passed.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn publish_event_handler(ctx: Context<PublishEvent>, _event_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let event = &mut ctx.accounts.event;
+
+    require!(
+        event.status == EventStatus::Draft,
+        EventError::EventNotDraft
+    );
+    event.validate_setup(clock.unix_timestamp)?;
+
+    if event.has_seat_map {
+        let seat_map = ctx
+            .accounts
+            .seat_map
+            .as_ref()
+            .ok_or(EventError::SeatRequired)?;
+        SeatMap::validate_sections(&seat_map.sections, event.total_tickets)?;
+    }
+
+    if event.has_lottery {
+        let lottery = ctx
+            .accounts
+            .lottery
+            .as_ref()
+            .ok_or(EventError::LotteryRequired)?;
+        lottery.config.validate(event, clock.slot)?;
+        require!(
+            lottery.config.entry_end > clock.unix_timestamp,
+            EventError::LotteryEntryClosed
+        );
+    }
+
+    if event.has_waitlist {
+        let waitlist = ctx
+            .accounts
+            .waitlist
+            .as_ref()
+            .ok_or(EventError::WaitlistRequired)?;
+        require!(waitlist.claim_window > 0, EventError::InvalidClaimWindow);
+    }
+
+    event.status = EventStatus::OnSale;
+
+    msg!("Event {} published", event.id);
+
+    Ok(())
+}
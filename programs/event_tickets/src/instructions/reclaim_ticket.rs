@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::{mint_to, MintTo};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants::{TICKET_OWNERSHIP_SEED, TICKET_REGISTRY_SEED, TICKET_SEED},
+    errors::EventError,
+    state::{Ticket, TicketOwnership, TicketRegistry},
+    utils::move_ticket_nft,
+};
+
+/// Contextual accounts required to bring a lent ticket back to its owner.
+#[derive(Accounts)]
+#[instruction(ticket_id: u64)]
+pub struct ReclaimTicket<'info> {
+    /// The ticket account being reclaimed.
+    #[account(
+        seeds = [TICKET_SEED, ticket.event.as_ref(), ticket_id.to_be_bytes().as_ref()],
+        bump = ticket.bump,
+    )]
+    pub ticket: Account<'info, Ticket>,
+
+    /// The ticket NFT mint. The ticket PDA moves the NFT as its permanent delegate and mint authority.
+    #[account(
+        mut,
+        address = ticket.mint,
+        extensions::permanent_delegate::delegate = ticket,
+    )]
+    pub ticket_mint: InterfaceAccount<'info, Mint>,
+
+    /// The ownership record of the ticket, where the loan is cleared.
+    #[account(
+        mut,
+        seeds = [TICKET_OWNERSHIP_SEED, ticket_mint.key().as_ref()],
+        bump,
+    )]
+    pub ticket_ownership: Account<'info, TicketOwnership>,
+
+    /// The borrower's Associated Token Account holding the ticket NFT. May be omitted if
+    /// the borrower burned the NFT and closed the account during the loan.
+    #[account(
+        mut,
+        associated_token::mint = ticket_mint,
+        associated_token::authority = ticket_ownership.user,
+        associated_token::token_program = token_program,
+    )]
+    pub borrower_ticket_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: The owner of the ticket, receiving it back.
+    #[account(address = ticket_ownership.owner @ EventError::Unauthorized)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// The owner's Associated Token Account, receiving the ticket NFT.
+    /// It is created again if the owner closed it during the loan.
+    #[account(
+        init_if_needed,
+        payer = caller,
+        associated_token::mint = ticket_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program,
+    )]
+    pub owner_ticket_ata: InterfaceAccount<'info, TokenAccount>,
+
+    /// Whoever reclaims the ticket: the owner, the borrower returning it early, or anyone
+    /// once the loan has ended. Pays for the owner's token account if it is recreated.
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// The event's ticket registry, consulted for check-ins recorded in a batch.
+    #[account(
+        seeds = [TICKET_REGISTRY_SEED, ticket.event.as_ref()],
+        bump = ticket_registry.load()?.bump,
+    )]
+    pub ticket_registry: Option<AccountLoader<'info, TicketRegistry>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for bringing a lent ticket back to its owner.
+///
+/// The borrower can return the ticket at any time. Once the loan deadline has passed or
+/// the ticket has been used, anyone can reclaim it without the borrower's signature,
+/// using the ticket PDA's authority over the mint. If the borrower burned the NFT, it is
+/// minted to the owner again.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_ticket_id` - The ID of the ticket, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn reclaim_ticket_handler(ctx: Context<ReclaimTicket>, _ticket_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let lent_until = ctx
+        .accounts
+        .ticket_ownership
+        .lent_until
+        .ok_or(EventError::TicketNotLent)?;
+
+    // Tickets checked in through a batch are only marked as used in the registry
+    let mut used = ctx.accounts.ticket.used;
+    if let Some(registry) = ctx.accounts.ticket_registry.as_ref() {
+        let registry_info = registry.to_account_info();
+        let data = registry_info.try_borrow_data()?;
+        used |= TicketRegistry::flags(&data, ctx.accounts.ticket.id)? & TicketRegistry::USED != 0;
+    }
+    require!(
+        used || clock.unix_timestamp > lent_until
+            || ctx.accounts.caller.key() == ctx.accounts.ticket_ownership.user,
+        EventError::LoanActive
+    );
+
+    let ticket_id_bytes = ctx.accounts.ticket.id.to_be_bytes();
+    let ticket_signer_seeds = &[
+        TICKET_SEED,
+        ctx.accounts.ticket.event.as_ref(),
+        &ticket_id_bytes,
+        &[ctx.accounts.ticket.bump],
+    ];
+
+    match ctx
+        .accounts
+        .borrower_ticket_ata
+        .as_ref()
+        .filter(|ata| ata.amount > 0)
+    {
+        Some(borrower_ticket_ata) => move_ticket_nft(
+            &ctx.accounts.token_program.to_account_info(),
+            &ctx.accounts.ticket_mint.to_account_info(),
+            &borrower_ticket_ata.to_account_info(),
+            &ctx.accounts.owner_ticket_ata.to_account_info(),
+            &ctx.accounts.ticket.to_account_info(),
+            &[&ticket_signer_seeds[..]],
+        )?,
+        None => {
+            // A borrower who burned the NFT cannot keep it from its owner, so it is minted again
+            require!(
+                ctx.accounts.ticket_mint.supply == 0,
+                EventError::BorrowerTicketAccountRequired
+            );
+            mint_to(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    MintTo {
+                        mint: ctx.accounts.ticket_mint.to_account_info(),
+                        to: ctx.accounts.owner_ticket_ata.to_account_info(),
+                        authority: ctx.accounts.ticket.to_account_info(),
+                    },
+                    &[&ticket_signer_seeds[..]],
+                ),
+                1,
+            )?;
+        }
+    }
+
+    let ownership = &mut ctx.accounts.ticket_ownership;
+    ownership.user = ownership.owner;
+    ownership.lent_until = None;
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{EVENT_ARCHIVE_SEED, EVENT_SEED, WAITLIST_ENTRY_SEED, WAITLIST_SEED},
+    errors::EventError,
+    state::{Event, EventArchive, EventStatus, Waitlist, WaitlistEntry, WaitlistStatus},
+};
+
+/// Contextual accounts required to reclaim an unused waitlist deposit.
+#[derive(Accounts)]
+#[instruction(event_id: u64, position: u64)]
+pub struct RefundWaitlistEntry<'info> {
+    /// The event the waitlist belongs to. Omitted once the event has been settled, in favor
+    /// of its archive.
+    #[account(
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Option<Box<Account<'info, Event>>>,
+
+    /// The archive of the event, provided instead of the event once it has been settled.
+    #[account(
+        seeds = [EVENT_ARCHIVE_SEED, waitlist.event.as_ref()],
+        bump = event_archive.bump,
+    )]
+    pub event_archive: Option<Account<'info, EventArchive>>,
+
+    /// The event's waitlist.
+    #[account(
+        seeds = [WAITLIST_SEED, waitlist.event.as_ref()],
+        bump = waitlist.bump,
+    )]
+    pub waitlist: Account<'info, Waitlist>,
+
+    /// The entry to close. Its deposit and rent are returned to the buyer.
+    #[account(
+        mut,
+        seeds = [WAITLIST_ENTRY_SEED, waitlist.key().as_ref(), position.to_be_bytes().as_ref()],
+        bump = waitlist_entry.bump,
+        has_one = buyer @ EventError::WaitlistEntryMismatch,
+        close = buyer,
+    )]
+    pub waitlist_entry: Account<'info, WaitlistEntry>,
+
+    /// The buyer who joined the waitlist.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+}
+
+/// Handles the logic for refunding a waitlist deposit.
+///
+/// Entries whose offer expired can be refunded at any time. Entries that never
+/// received an offer can be refunded once the event has ended or been cancelled,
+/// including after it has been settled.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `_position` - The position of the entry, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn refund_waitlist_entry_handler(
+    ctx: Context<RefundWaitlistEntry>,
+    _event_id: u64,
+    _position: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let status = match (
+        ctx.accounts.event.as_deref(),
+        ctx.accounts.event_archive.as_ref(),
+    ) {
+        (Some(event), None) => {
+            require_keys_eq!(
+                event.key(),
+                ctx.accounts.waitlist.event,
+                EventError::InvalidEventAccounts
+            );
+            event.status_at(clock.unix_timestamp)
+        }
+        (None, Some(_)) => EventStatus::Settled,
+        _ => return err!(EventError::InvalidEventAccounts),
+    };
+
+    let refundable = match ctx.accounts.waitlist_entry.status {
+        WaitlistStatus::Expired => true,
+        WaitlistStatus::Queued => matches!(
+            status,
+            EventStatus::Ended | EventStatus::Cancelled | EventStatus::Settled
+        ),
+        WaitlistStatus::Offered => false,
+    };
+    require!(refundable, EventError::WaitlistRefundUnavailable);
+
+    // The entry account, including the deposit, is closed to the buyer by Anchor
+    msg!("Waitlist deposit refunded");
+
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions as sysvar_instructions;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::{
+    constants::{
+        DISCRIMINATOR_LENGHT, EVENT_SEED, GATEKEEPER_PASS_SEED, LOYALTY_SEED, RESERVATION_SEED,
+    },
+    errors::EventError,
+    state::{
+        Event, EventStatus, GatekeeperPass, Installment, LoyaltyRecord, PricingMode, Reservation,
+    },
+    utils::verify_purchase_approval,
+};
+
+/// Contextual accounts required to reserve a ticket with a deposit.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct ReserveTicket<'info> {
+    /// The event the ticket is reserved for.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The reservation, which holds the deposit and the installments until the ticket is claimed.
+    #[account(
+        init,
+        payer = buyer,
+        space = DISCRIMINATOR_LENGHT + Reservation::INIT_SPACE,
+        seeds = [RESERVATION_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub reservation: Account<'info, Reservation>,
+
+    /// The buyer reserving the ticket. Pays the deposit and the reservation rent.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// CHECK: The instructions sysvar, used to find the buyer's attestation and gatekeeper token.
+    /// Required when the event only sells tickets to attested buyers, or when its gatekeeper
+    /// approves the purchase with a token.
+    #[account(address = sysvar_instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// The event's gatekeeper, co-signing the reservation. Required when the event has a
+    /// gatekeeper, unless the transaction carries a gatekeeper token.
+    pub gatekeeper: Option<Signer<'info>>,
+
+    /// The buyer's gatekeeper pass, recording the tokens used so that each buys once.
+    /// Required when the reservation is approved with a gatekeeper token.
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = DISCRIMINATOR_LENGHT + GatekeeperPass::INIT_SPACE,
+        seeds = [GATEKEEPER_PASS_SEED, event.key().as_ref(), buyer.key().as_ref()],
+        bump,
+    )]
+    pub gatekeeper_pass: Option<Box<Account<'info, GatekeeperPass>>>,
+
+    /// The buyer's attendance record with the event's admin. Required for the buyer to
+    /// get the event's loyalty perks.
+    #[account(
+        seeds = [LOYALTY_SEED, event.admin.as_ref(), buyer.key().as_ref()],
+        bump = loyalty.bump,
+    )]
+    pub loyalty: Option<Box<Account<'info, LoyaltyRecord>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for reserving a ticket to be paid in installments.
+///
+/// The buyer pays the deposit of the event's installment plan, and the ticket is held
+/// against `total_tickets` until it is claimed with `mint_ticket` once fully paid, or
+/// the reservation is cancelled. The rest of the price, locked at the current ticket
+/// price after the buyer's loyalty discount, is split into equal installments due every `interval` seconds.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn reserve_ticket_handler(ctx: Context<ReserveTicket>, _event_id: u64) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    let clock = Clock::get()?;
+
+    event.require_status(clock.unix_timestamp, EventStatus::SELLING)?;
+    let plan = event
+        .installment_plan
+        .ok_or(EventError::InstallmentsNotEnabled)?;
+    require!(
+        !event.has_seat_map
+            && !event.has_lottery
+            && !event.has_waitlist
+            && event.lite_registry.is_none()
+            && !matches!(event.pricing, PricingMode::DutchAuction(_)),
+        EventError::InstallmentsUnsupported
+    );
+
+    let token = verify_purchase_approval(
+        event,
+        ctx.accounts.instructions_sysvar.as_deref(),
+        ctx.accounts.gatekeeper.as_ref().map(|signer| signer.key()),
+        &ctx.accounts.buyer.key(),
+        &ctx.accounts.buyer.key(),
+        &clock,
+    )?;
+
+    // Each gatekeeper token approves a single purchase
+    if let Some(token) = token {
+        match (
+            ctx.accounts.gatekeeper_pass.as_mut(),
+            ctx.bumps.gatekeeper_pass,
+        ) {
+            (Some(pass), Some(bump)) => pass.consume(&token, bump)?,
+            _ => return err!(EventError::GatekeeperPassRequired),
+        }
+    }
+
+    require!(
+        event.tickets_sold + event.tickets_reserved < event.total_tickets + event.tickets_returned,
+        EventError::EventSoldOut
+    );
+
+    // Split the price into the deposit and equal installments, the last one taking the remainder
+    let price = event.loyalty_price(
+        ctx.accounts.loyalty.as_deref().map(|record| &**record),
+        event.current_price(event.ticket_price, clock.unix_timestamp)?,
+        clock.unix_timestamp,
+    )?;
+    let deposit = (price as u128 * plan.deposit_bps as u128 / 10_000) as u64;
+    let balance = price - deposit;
+    let installments = plan.installments as u64;
+    let mut schedule = Vec::with_capacity(plan.installments as usize);
+    for index in 1..=installments {
+        let mut amount = balance / installments;
+        if index == installments {
+            amount += balance % installments;
+        }
+        let due = plan
+            .interval
+            .checked_mul(index as i64)
+            .and_then(|offset| clock.unix_timestamp.checked_add(offset))
+            .ok_or(EventError::NumericOverflow)?;
+        schedule.push(Installment { amount, due });
+    }
+    require!(
+        schedule
+            .last()
+            .is_some_and(|installment| installment.due <= event.start_time),
+        EventError::InstallmentScheduleTooLong
+    );
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer.to_account_info(),
+                to: ctx.accounts.reservation.to_account_info(),
+            },
+        ),
+        deposit,
+    )?;
+
+    let reservation = &mut ctx.accounts.reservation;
+    reservation.event = event.key();
+    reservation.buyer = ctx.accounts.buyer.key();
+    reservation.price = price;
+    reservation.deposit = deposit;
+    reservation.paid = deposit;
+    reservation.schedule = schedule;
+    reservation.bump = ctx.bumps.reservation;
+
+    event.tickets_reserved = event
+        .tickets_reserved
+        .checked_add(1)
+        .ok_or(EventError::NumericOverflow)?;
+
+    Ok(())
+}
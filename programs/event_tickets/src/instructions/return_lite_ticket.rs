@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{EVENT_SEED, VAULT_SEED},
+    errors::EventError,
+    state::{Event, EventStatus, EventVault, LiteRegistry, LiteTicket},
+};
+
+/// Contextual accounts required to return a lite ticket and get a refund.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct ReturnLiteTicket<'info> {
+    /// The event the ticket belongs to.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event's vault account, from which the refund is paid.
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, event.key().as_ref()],
+        bump,
+    )]
+    pub event_vault: Account<'info, EventVault>,
+
+    /// The event's lite ticket registry.
+    #[account(
+        mut,
+        address = event.lite_registry.ok_or(EventError::LiteModeNotEnabled)? @ EventError::LiteModeNotEnabled,
+    )]
+    pub lite_registry: AccountLoader<'info, LiteRegistry>,
+
+    /// The holder returning the ticket. Must be a signer and receives the refund.
+    #[account(mut)]
+    pub holder: Signer<'info>,
+}
+
+/// Handles the logic for returning a lite ticket.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `ticket_id` - The ID of the ticket in the registry.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn return_lite_ticket_handler(
+    ctx: Context<ReturnLiteTicket>,
+    _event_id: u64,
+    ticket_id: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts.event.require_status(
+        clock.unix_timestamp,
+        &[
+            EventStatus::OnSale,
+            EventStatus::SalesClosed,
+            EventStatus::Live,
+            EventStatus::Cancelled,
+        ],
+    )?;
+
+    let registry_info = ctx.accounts.lite_registry.to_account_info();
+    let mut data = registry_info.try_borrow_mut_data()?;
+    let mut ticket = LiteRegistry::read_entry(&data, ticket_id)?;
+
+    require!(
+        ticket.status != LiteTicket::USED,
+        EventError::TicketAlreadyUsed
+    );
+    require!(
+        ticket.status == LiteTicket::VALID,
+        EventError::InvalidLiteTicket
+    );
+    require!(
+        ticket.owner == ctx.accounts.holder.key(),
+        EventError::TicketHolderMismatch
+    );
+
+    ticket.status = LiteTicket::RETURNED;
+    LiteRegistry::write_entry(&mut data, ticket_id, &ticket)?;
+
+    // Update Event State
+    ctx.accounts.event.tickets_returned = ctx
+        .accounts
+        .event
+        .tickets_returned
+        .checked_add(1)
+        .ok_or(EventError::NumericOverflow)?;
+
+    // Refund the price that was actually paid for the ticket
+    **ctx
+        .accounts
+        .event_vault
+        .to_account_info()
+        .try_borrow_mut_lamports()? -= ticket.price_paid;
+    **ctx
+        .accounts
+        .holder
+        .to_account_info()
+        .try_borrow_mut_lamports()? += ticket.price_paid;
+
+    Ok(())
+}
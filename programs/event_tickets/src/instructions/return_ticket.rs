@@ -1,14 +1,20 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token_2022::{burn_checked, close_account, BurnChecked, CloseAccount},
+    token_2022::{close_account, CloseAccount},
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::{
-    constants::{EVENT_SEED, TICKET_SEED, VAULT_SEED},
+    constants::{
+        EVENT_SEED, EXTRA_ACCOUNTS_METAS_SEED, SEAT_MAP_SEED, TICKET_OWNERSHIP_SEED,
+        TICKET_REGISTRY_SEED, TICKET_SEED, VAULT_SEED, WAITLIST_SEED,
+    },
     errors::EventError,
-    state::{Event, EventVault, Ticket},
+    state::{
+        Event, EventStatus, EventVault, SeatMap, Ticket, TicketOwnership, TicketRegistry, Waitlist,
+    },
+    utils::{burn_and_close_ticket_mint, close_program_account},
 };
 
 /// Contextual accounts required for a buyer to return their ticket and get a refund.
@@ -32,22 +38,43 @@ pub struct ReturnTicket<'info> {
     )]
     pub event_vault: Account<'info, EventVault>,
 
-    /// The ticket account to be returned. It will be closed after the instruction.
+    /// The ticket account to be returned. It will be closed after the instruction,
+    /// with its rent returned to the rent recipient.
     #[account(
         mut,
         seeds = [TICKET_SEED, event.key().as_ref(), ticket_id.to_be_bytes().as_ref()],
         bump = ticket.bump,
-        close=signer,
+        close = rent_recipient,
     )]
     pub ticket: Account<'info, Ticket>,
 
     /// The ticket NFT mint account, which is a unique SPL token representing the ticket.
+    /// It is closed by the ticket PDA, its close authority.
     #[account(
         mut,
-        address=ticket.mint,
+        address = ticket.mint,
+        extensions::permanent_delegate::delegate = ticket,
+        extensions::close_authority::authority = ticket,
     )]
     pub ticket_mint: InterfaceAccount<'info, Mint>,
 
+    /// The ownership record of the ticket, closed along with it.
+    #[account(
+        mut,
+        seeds = [TICKET_OWNERSHIP_SEED, ticket_mint.key().as_ref()],
+        bump,
+        close = rent_recipient,
+    )]
+    pub ticket_ownership: Account<'info, TicketOwnership>,
+
+    /// CHECK: The ExtraAccountMetaList of the ticket mint, closed along with it.
+    #[account(
+        mut,
+        seeds = [EXTRA_ACCOUNTS_METAS_SEED, ticket_mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
     /// The buyer who is returning the ticket. Must be the owner of the ticket.
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -61,6 +88,41 @@ pub struct ReturnTicket<'info> {
     )]
     pub signer_ticket_ata: InterfaceAccount<'info, TokenAccount>,
 
+    /// CHECK: The wallet receiving the reclaimed rent: the ticket's rent payer, or the
+    /// returning holder if the payer opted for that.
+    #[account(
+        mut,
+        address = ticket.rent_recipient(signer.key()) @ EventError::RentRecipientMismatch,
+    )]
+    pub rent_recipient: UncheckedAccount<'info>,
+
+    /// The event's seat map, where the returned ticket's seat is released.
+    /// Required when the ticket is for a reserved seat.
+    #[account(
+        mut,
+        seeds = [SEAT_MAP_SEED, event.key().as_ref()],
+        bump = seat_map.bump,
+    )]
+    pub seat_map: Option<Account<'info, SeatMap>>,
+
+    /// The event's waitlist, for which the returned ticket is reserved.
+    /// Required when the event has a waitlist.
+    #[account(
+        mut,
+        seeds = [WAITLIST_SEED, event.key().as_ref()],
+        bump = waitlist.bump,
+    )]
+    pub waitlist: Option<Account<'info, Waitlist>>,
+
+    /// The event's ticket registry, where the return is recorded.
+    /// Required when the event tracks ticket statuses in a registry.
+    #[account(
+        mut,
+        seeds = [TICKET_REGISTRY_SEED, event.key().as_ref()],
+        bump = ticket_registry.load()?.bump,
+    )]
+    pub ticket_registry: Option<AccountLoader<'info, TicketRegistry>>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -68,8 +130,9 @@ pub struct ReturnTicket<'info> {
 
 /// Handles the logic for returning a ticket.
 ///
-/// This instruction validates the ticket, refunds the current holder, burns the NFT,
-/// and closes the ticket account.
+/// This instruction validates the ticket, refunds the current holder, burns the NFT and
+/// closes every account created for the ticket: the ticket itself, its mint, the holder's
+/// token account, the ownership record and the transfer hook's ExtraAccountMetaList.
 ///
 /// # Arguments
 ///
@@ -86,25 +149,59 @@ pub fn return_ticket_handler(
     _ticket_id: u64,
 ) -> Result<()> {
     let clock = Clock::get()?;
+    ctx.accounts.event.require_status(
+        clock.unix_timestamp,
+        &[
+            EventStatus::OnSale,
+            EventStatus::SalesClosed,
+            EventStatus::Live,
+            EventStatus::Cancelled,
+        ],
+    )?;
+    require!(!ctx.accounts.ticket.used, EventError::TicketAlreadyUsed);
     require!(
-        ctx.accounts.ticket.valid_until >= clock.unix_timestamp,
-        EventError::TicketExpired
+        ctx.accounts.ticket_ownership.lent_until.is_none(),
+        EventError::TicketOnLoan
     );
-    require!(!ctx.accounts.ticket.used, EventError::TicketAlreadyUsed);
 
-    // Burn the NFT
-    let cpi_accounts = BurnChecked {
-        mint: ctx.accounts.ticket_mint.to_account_info(),
-        from: ctx.accounts.signer_ticket_ata.to_account_info(),
-        authority: ctx.accounts.signer.to_account_info(),
-    };
-    burn_checked(
-        CpiContext::new(ctx.accounts.token_program.to_account_info(), cpi_accounts),
-        1,
-        0,
+    // Tickets checked in through a batch are only marked as used in the registry
+    if ctx.accounts.event.has_ticket_registry {
+        let registry_info = ctx
+            .accounts
+            .ticket_registry
+            .as_ref()
+            .ok_or(EventError::TicketRegistryRequired)?
+            .to_account_info();
+        let mut data = registry_info.try_borrow_mut_data()?;
+        let ticket_id = ctx.accounts.ticket.id;
+        require!(
+            TicketRegistry::flags(&data, ticket_id)? & TicketRegistry::USED == 0,
+            EventError::TicketAlreadyUsed
+        );
+        TicketRegistry::set_flags(&mut data, ticket_id, TicketRegistry::RETURNED)?;
+    }
+
+    let event_pubkey = ctx.accounts.event.key();
+    let ticket_id_bytes = ctx.accounts.ticket.id.to_be_bytes();
+    let ticket_signer_seeds = &[
+        TICKET_SEED,
+        event_pubkey.as_ref(),
+        &ticket_id_bytes,
+        &[ctx.accounts.ticket.bump],
+    ];
+
+    // Burn the NFT and close its mint, both signed by the ticket PDA
+    burn_and_close_ticket_mint(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.ticket_mint.to_account_info(),
+        &ctx.accounts.signer_ticket_ata.to_account_info(),
+        &ctx.accounts.ticket.to_account_info(),
+        &ctx.accounts.rent_recipient.to_account_info(),
+        &[&ticket_signer_seeds[..]],
     )?;
 
-    // Close the buyer's token account
+    // Close the holder's token account. It belongs to the holder, who may have funded it
+    // on a transfer, so its rent goes to them rather than to the rent recipient.
     let cpi_accounts = CloseAccount {
         account: ctx.accounts.signer_ticket_ata.to_account_info(),
         destination: ctx.accounts.signer.to_account_info(),
@@ -115,6 +212,35 @@ pub fn return_ticket_handler(
         cpi_accounts,
     ))?;
 
+    // The transfer hook accounts are no longer needed once the mint is gone
+    close_program_account(
+        &ctx.accounts.extra_account_meta_list.to_account_info(),
+        &ctx.accounts.rent_recipient.to_account_info(),
+    )?;
+
+    // Release the reserved seat so it can be sold again
+    if let Some(selection) = ctx.accounts.ticket.seat {
+        let seat_map = ctx
+            .accounts
+            .seat_map
+            .as_mut()
+            .ok_or(EventError::SeatRequired)?;
+        let index = seat_map.seat_index(&selection)?;
+        seat_map.set_sold(index, false);
+    }
+
+    // Reserve the freed ticket for the next buyer in the waitlist
+    if ctx.accounts.event.has_waitlist {
+        let waitlist = ctx
+            .accounts
+            .waitlist
+            .as_mut()
+            .ok_or(EventError::WaitlistRequired)?;
+        if waitlist.open_slots < waitlist.queued() {
+            waitlist.open_slots += 1;
+        }
+    }
+
     // Update Event State
     ctx.accounts.event.tickets_returned = ctx
         .accounts
@@ -123,17 +249,18 @@ pub fn return_ticket_handler(
         .checked_add(1)
         .ok_or(EventError::NumericOverflow)?;
 
+    // Refund the price that was actually paid for the ticket
     **ctx
         .accounts
         .event_vault
         .to_account_info()
-        .try_borrow_mut_lamports()? -= ctx.accounts.event.ticket_price;
+        .try_borrow_mut_lamports()? -= ctx.accounts.ticket.price_paid;
 
     **ctx
         .accounts
         .signer
         .to_account_info()
-        .try_borrow_mut_lamports()? += ctx.accounts.event.ticket_price;
+        .try_borrow_mut_lamports()? += ctx.accounts.ticket.price_paid;
 
     Ok(())
 }
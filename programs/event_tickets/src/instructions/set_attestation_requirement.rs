@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::EVENT_SEED,
+    errors::EventError,
+    state::{AttestationRequirement, Event, EventStatus},
+};
+
+/// Contextual accounts required to set the attestation buyers of an event must present.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct SetAttestationRequirement<'info> {
+    /// The event whose ticket sales are being restricted.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+}
+
+/// Handles the logic for setting or clearing the attestation buyers of an event must present.
+///
+/// While a requirement is set, `mint_ticket` and `mint_lite_ticket` only succeed when the
+/// transaction carries an unexpired attestation of the required claim about the recipient,
+/// signed by the trusted issuer, see `verify_attestation`.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `requirement` - The trusted issuer and required claim, or `None` to sell to anyone.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn set_attestation_requirement_handler(
+    ctx: Context<SetAttestationRequirement>,
+    _event_id: u64,
+    requirement: Option<AttestationRequirement>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    event.require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+
+    event.attestation = requirement;
+
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::EVENT_SEED,
+    errors::EventError,
+    state::{Event, EventStatus},
+};
+
+/// Contextual accounts required to set the gatekeeper of an event.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct SetGatekeeper<'info> {
+    /// The event whose purchases the gatekeeper approves.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+}
+
+/// Handles the logic for setting or clearing the gatekeeper of an event.
+///
+/// While a gatekeeper is set, `mint_ticket` and `mint_lite_ticket` only succeed when
+/// the gatekeeper co-signs the purchase, or when the transaction carries a
+/// `GatekeeperToken` the gatekeeper signed for the event and the buyer at a recent
+/// slot, see `verify_gatekeeper_token`. Each token buys once, as the buyer's
+/// `GatekeeperPass` records the last one used. This lets a front end put a human
+/// check or a queue in front of purchases.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `gatekeeper` - The gatekeeper's key, or `None` to let anyone buy directly.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn set_gatekeeper_handler(
+    ctx: Context<SetGatekeeper>,
+    _event_id: u64,
+    gatekeeper: Option<Pubkey>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    event.require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+
+    event.gatekeeper = gatekeeper;
+
+    Ok(())
+}
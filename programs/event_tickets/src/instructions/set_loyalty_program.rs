@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::EVENT_SEED,
+    errors::EventError,
+    state::{Discount, Event, EventStatus, LoyaltyProgram},
+};
+
+/// Contextual accounts required to set the loyalty program of an event.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct SetLoyaltyProgram<'info> {
+    /// The event whose tickets the loyalty program applies to.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+}
+
+/// Handles the logic for setting or clearing the loyalty program of an event.
+///
+/// Wallets that checked in to at least `min_events` of the admin's events, as recorded
+/// in their `LoyaltyRecord` by `use_ticket`, get the program's discount and can buy
+/// tickets during its presale. Perks go to the wallet receiving the ticket, or to the
+/// buyer of an installment reservation.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `program` - The loyalty program, or `None` to treat every buyer the same.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn set_loyalty_program_handler(
+    ctx: Context<SetLoyaltyProgram>,
+    _event_id: u64,
+    program: Option<LoyaltyProgram>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    event.require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+
+    if let Some(program) = &program {
+        require!(
+            program.min_events > 0
+                && (program.discount.is_some() || program.presale_end.is_some())
+                && program.presale_end.unwrap_or(event.start_time) <= event.start_time,
+            EventError::InvalidLoyaltyProgram
+        );
+        match program.discount {
+            Some(Discount::Percentage { bps }) => {
+                require!(bps > 0 && bps <= 10_000, EventError::InvalidDiscount)
+            }
+            Some(Discount::Fixed { amount }) => require!(amount > 0, EventError::InvalidDiscount),
+            None => {}
+        }
+    }
+
+    event.loyalty = program;
+
+    Ok(())
+}
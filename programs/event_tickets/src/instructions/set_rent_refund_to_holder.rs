@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{EVENT_SEED, TICKET_SEED},
+    errors::EventError,
+    state::{Event, Ticket},
+};
+
+/// Contextual accounts required to choose where a ticket's rent is refunded.
+#[derive(Accounts)]
+#[instruction(event_id: u64, ticket_id: u64)]
+pub struct SetRentRefundToHolder<'info> {
+    /// The event the ticket belongs to.
+    #[account(
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The ticket whose rent refund is configured.
+    #[account(
+        mut,
+        seeds = [TICKET_SEED, event.key().as_ref(), ticket_id.to_be_bytes().as_ref()],
+        bump = ticket.bump,
+        constraint = ticket.payer == payer.key() @ EventError::NotRentPayer,
+    )]
+    pub ticket: Account<'info, Ticket>,
+
+    /// The wallet that paid the rent of the ticket's accounts. Must be a signer.
+    pub payer: Signer<'info>,
+}
+
+/// Handles the logic for choosing where a ticket's rent is refunded.
+///
+/// By default the rent goes back to the payer. A payer who bought the ticket as a gift,
+/// or sold it on, can hand the rent over to whoever holds the ticket when it is closed.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `_ticket_id` - The ID of the ticket, used for PDA validation.
+/// * `enabled` - Whether the rent goes to the holder rather than the payer.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn set_rent_refund_to_holder_handler(
+    ctx: Context<SetRentRefundToHolder>,
+    _event_id: u64,
+    _ticket_id: u64,
+    enabled: bool,
+) -> Result<()> {
+    ctx.accounts.ticket.refund_rent_to_holder = enabled;
+    Ok(())
+}
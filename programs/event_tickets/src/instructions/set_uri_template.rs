@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_LENGHT, EVENT_SEED},
+    errors::EventError,
+    state::{Event, EventStatus},
+    utils::resize_account,
+};
+
+/// Contextual accounts required to set the metadata URI template of an event.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct SetUriTemplate<'info> {
+    /// The event whose URI template is being set.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    /// Pays for or receives the rent difference when the event account is resized.
+    #[account(mut, address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+
+    /// The system program, required for topping up the event account.
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for setting or clearing the metadata URI template of an event.
+///
+/// The template is expanded for every ticket minted afterwards, see `Event::ticket_uri`.
+/// `{tier}` and `{seat}` can only be used once the event has a seat map.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `uri_template` - The template, or `None` to give every ticket the event's metadata URI.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn set_uri_template_handler(
+    ctx: Context<SetUriTemplate>,
+    _event_id: u64,
+    uri_template: Option<String>,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    event.require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+
+    if let Some(template) = &uri_template {
+        require!(template.len() <= 200, EventError::UriTooLong);
+
+        let mut rest = template.as_str();
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..]
+                .find('}')
+                .ok_or(EventError::InvalidUriTemplate)?;
+            match &rest[start + 1..start + end] {
+                "id" => {}
+                "tier" | "seat" => require!(event.has_seat_map, EventError::InvalidUriTemplate),
+                _ => return err!(EventError::InvalidUriTemplate),
+            }
+            rest = &rest[start + end + 1..];
+        }
+    }
+
+    event.uri_template = uri_template;
+
+    // Fit the event account to the new strings
+    resize_account(
+        &event.to_account_info(),
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        DISCRIMINATOR_LENGHT + event.space(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::EVENT_SEED,
+    errors::EventError,
+    state::{Event, EventStatus, LiteRegistry, LiteTicket},
+};
+
+/// Contextual accounts required to transfer a lite ticket to another wallet.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct TransferLiteTicket<'info> {
+    /// The event the ticket belongs to.
+    #[account(
+        seeds = [EVENT_SEED, event.admin.as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event's lite ticket registry.
+    #[account(
+        mut,
+        address = event.lite_registry.ok_or(EventError::LiteModeNotEnabled)? @ EventError::LiteModeNotEnabled,
+    )]
+    pub lite_registry: AccountLoader<'info, LiteRegistry>,
+
+    /// The current holder of the ticket. Must be a signer.
+    pub holder: Signer<'info>,
+
+    /// CHECK: The wallet receiving the ticket.
+    pub new_owner: UncheckedAccount<'info>,
+}
+
+/// Handles the logic for transferring a lite ticket.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `ticket_id` - The ID of the ticket in the registry.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn transfer_lite_ticket_handler(
+    ctx: Context<TransferLiteTicket>,
+    _event_id: u64,
+    ticket_id: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts
+        .event
+        .require_status(clock.unix_timestamp, EventStatus::ACTIVE)?;
+
+    let registry_info = ctx.accounts.lite_registry.to_account_info();
+    let mut data = registry_info.try_borrow_mut_data()?;
+    let mut ticket = LiteRegistry::read_entry(&data, ticket_id)?;
+
+    require!(
+        ticket.status == LiteTicket::VALID,
+        EventError::InvalidLiteTicket
+    );
+    require!(
+        ticket.owner == ctx.accounts.holder.key(),
+        EventError::TicketHolderMismatch
+    );
+
+    ticket.owner = ctx.accounts.new_owner.key();
+    LiteRegistry::write_entry(&mut data, ticket_id, &ticket)
+}
@@ -15,6 +15,7 @@ use anchor_spl::token_interface::{Mint, TokenAccount};
 use spl_transfer_hook_interface::error::TransferHookError;
 
 use crate::constants::{EXTRA_ACCOUNTS_METAS_SEED, TICKET_OWNERSHIP_SEED};
+use crate::errors::EventError;
 use crate::state::TicketOwnership;
 
 #[derive(Accounts)]
@@ -55,7 +56,12 @@ pub fn transfer_hook_handler(ctx: Context<TransferHook>, _amount: u64) -> Result
     check_token_account_is_transferring(&source_account.to_account_info().try_borrow_data()?)?;
     check_token_account_is_transferring(&destination_account.to_account_info().try_borrow_data()?)?;
 
-    ctx.accounts.ticket_ownership.owner = ctx.accounts.destination_token.owner;
+    // Lent tickets stay with the borrower until they are reclaimed
+    let ownership = &mut ctx.accounts.ticket_ownership;
+    require!(ownership.lent_until.is_none(), EventError::TicketOnLoan);
+
+    ownership.owner = ctx.accounts.destination_token.owner;
+    ownership.user = ctx.accounts.destination_token.owner;
 
     msg!(
         "Ticket owner updated to: {}",
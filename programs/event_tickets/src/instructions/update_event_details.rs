@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_LENGHT, EVENT_SEED},
+    errors::EventError,
+    state::{Event, EventDetails, EventStatus},
+    utils::resize_account,
+};
+
+/// Contextual accounts required to update the structured details of an event.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct UpdateEventDetails<'info> {
+    /// The event whose details are being updated.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    /// Pays for or receives the rent difference when the event account is resized.
+    #[account(mut, address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+
+    /// The system program, required for topping up the event account.
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for updating the venue, category and age rating of an event.
+///
+/// The details are copied into the metadata of every ticket minted afterwards;
+/// tickets that were already minted keep the details they were minted with.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `details` - The new details of the event.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn update_event_details_handler(
+    ctx: Context<UpdateEventDetails>,
+    _event_id: u64,
+    details: EventDetails,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    event.require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+    details.validate()?;
+
+    event.details = details;
+
+    // Fit the event account to the new strings
+    resize_account(
+        &event.to_account_info(),
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        DISCRIMINATOR_LENGHT + event.space(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_LENGHT, EVENT_SEED},
+    errors::EventError,
+    state::{Event, EventStatus},
+    utils::resize_account,
+};
+
+/// Contextual accounts required to update the name, symbol, description and metadata URI of an event.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct UpdateEventInfo<'info> {
+    /// The event being updated.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    /// Pays for or receives the rent difference when the event account is resized.
+    #[account(mut, address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+
+    /// The system program, required for topping up the event account.
+    pub system_program: Program<'info, System>,
+}
+
+/// Handles the logic for updating the name, symbol, description and metadata URI of an event.
+///
+/// The event account is resized to fit the new strings. The admin pays the additional
+/// rent when it grows and is refunded the rent it no longer needs when it shrinks.
+/// Tickets that were already minted keep the metadata they were minted with.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `name` - The new name of the event.
+/// * `symbol` - The new symbol of the event.
+/// * `description` - The new description of the event.
+/// * `metadata_uri` - The new URI pointing to additional metadata about the event.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn update_event_info_handler(
+    ctx: Context<UpdateEventInfo>,
+    _event_id: u64,
+    name: String,
+    symbol: String,
+    description: String,
+    metadata_uri: String,
+) -> Result<()> {
+    let event = &mut ctx.accounts.event;
+    event.require_status(Clock::get()?.unix_timestamp, EventStatus::CONFIGURABLE)?;
+
+    // Validation
+    require!(name.len() >= 3, EventError::NameTooShort);
+    require!(name.len() <= 100, EventError::NameTooLong);
+    require!(symbol.len() <= 10, EventError::SymbolTooLong);
+    require!(description.len() <= 500, EventError::DescriptionTooLong);
+    require!(metadata_uri.len() <= 200, EventError::UriTooLong);
+
+    event.name = name;
+    event.symbol = symbol;
+    event.description = description;
+    event.metadata_uri = metadata_uri;
+
+    // Fit the event account to the new strings
+    resize_account(
+        &event.to_account_info(),
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        DISCRIMINATOR_LENGHT + event.space(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::EVENT_SEED,
+    errors::EventError,
+    state::{Event, EventStatus},
+};
+
+/// Contextual accounts required for the organizer to change the status of an event.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct UpdateEventStatus<'info> {
+    /// The event whose status is being changed.
+    #[account(
+        mut,
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event administrator. Must be a signer and match the admin on the event account.
+    #[account(address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+}
+
+/// Handles the logic for an organizer-driven status transition.
+///
+/// The organizer can pause and resume ticket sales, and cancel an event that has not
+/// ended. The transition is checked against the current status, including time-based
+/// transitions that have not been recorded yet.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `status` - The new status of the event.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn update_event_status_handler(
+    ctx: Context<UpdateEventStatus>,
+    _event_id: u64,
+    status: EventStatus,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let event = &mut ctx.accounts.event;
+
+    require!(
+        event
+            .status_at(clock.unix_timestamp)
+            .admin_can_transition_to(status),
+        EventError::InvalidStatusTransition
+    );
+
+    event.status = status;
+
+    msg!("Event {} status updated", event.id);
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::EVENT_SEED,
+    errors::EventError,
+    state::{Event, EventStatus, LiteRegistry, LiteTicket},
+};
+
+/// Contextual accounts required to check in a lite ticket.
+#[derive(Accounts)]
+#[instruction(event_id: u64)]
+pub struct UseLiteTicket<'info> {
+    /// The event the ticket belongs to.
+    #[account(
+        seeds = [EVENT_SEED, admin.key().as_ref(), event_id.to_be_bytes().as_ref()],
+        bump = event.bump,
+    )]
+    pub event: Account<'info, Event>,
+
+    /// The event's lite ticket registry.
+    #[account(
+        mut,
+        address = event.lite_registry.ok_or(EventError::LiteModeNotEnabled)? @ EventError::LiteModeNotEnabled,
+    )]
+    pub lite_registry: AccountLoader<'info, LiteRegistry>,
+
+    /// The administrator of the event. Their signature is required to authorize this action.
+    #[account(address = event.admin @ EventError::AuthorityMismatch)]
+    pub admin: Signer<'info>,
+
+    /// The holder of the ticket. Their signature proves they are present at the entrance.
+    pub holder: Signer<'info>,
+}
+
+/// Handles the logic for checking in a lite ticket.
+///
+/// Unlike NFT tickets, there is no token to inspect at the door, so both the
+/// event administrator and the ticket holder must sign.
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing all necessary accounts.
+/// * `_event_id` - The ID of the event, used for PDA validation.
+/// * `ticket_id` - The ID of the ticket in the registry.
+///
+/// # Returns
+///
+/// An empty `Result` indicating success or failure.
+pub fn use_lite_ticket_handler(
+    ctx: Context<UseLiteTicket>,
+    _event_id: u64,
+    ticket_id: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    ctx.accounts
+        .event
+        .require_status(clock.unix_timestamp, EventStatus::ACTIVE)?;
+
+    let registry_info = ctx.accounts.lite_registry.to_account_info();
+    let mut data = registry_info.try_borrow_mut_data()?;
+    let mut ticket = LiteRegistry::read_entry(&data, ticket_id)?;
+
+    require!(
+        ticket.status != LiteTicket::USED,
+        EventError::TicketAlreadyUsed
+    );
+    require!(
+        ticket.status == LiteTicket::VALID,
+        EventError::InvalidLiteTicket
+    );
+    require!(
+        ticket.owner == ctx.accounts.holder.key(),
+        EventError::TicketHolderMismatch
+    );
+
+    ticket.status = LiteTicket::USED;
+    LiteRegistry::write_entry(&mut data, ticket_id, &ticket)
+}
@@ -1,9 +1,15 @@
 use crate::{
-    constants::{EVENT_SEED, TICKET_SEED},
+    constants::{
+        DISCRIMINATOR_LENGHT, EVENT_SEED, LOYALTY_SEED, METADATA_CHECKED_IN_AT_KEY,
+        METADATA_STATUS_KEY, TICKET_OWNERSHIP_SEED, TICKET_REGISTRY_SEED, TICKET_SEED,
+        TICKET_STATUS_USED,
+    },
     errors::EventError,
-    state::{Event, Ticket},
+    state::{Event, EventStatus, LoyaltyRecord, Ticket, TicketOwnership, TicketRegistry},
+    utils::update_ticket_metadata,
 };
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
 
 /// Contextual accounts required to mark a ticket as used.
 #[derive(Accounts)]
@@ -25,15 +31,55 @@ pub struct UseTicket<'info> {
     )]
     pub ticket: Account<'info, Ticket>,
 
+    /// The ticket NFT mint, whose metadata is updated to reflect the check-in.
+    #[account(
+        mut,
+        address = ticket.mint,
+    )]
+    pub ticket_mint: InterfaceAccount<'info, Mint>,
+
+    /// The ownership record of the ticket, identifying the wallet checking in.
+    #[account(
+        seeds = [TICKET_OWNERSHIP_SEED, ticket_mint.key().as_ref()],
+        bump,
+    )]
+    pub ticket_ownership: Account<'info, TicketOwnership>,
+
+    /// The attendance record of the wallet checking in with the event's admin, where the
+    /// check-in is counted. Created on the wallet's first check-in.
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = DISCRIMINATOR_LENGHT + LoyaltyRecord::INIT_SPACE,
+        seeds = [LOYALTY_SEED, event.admin.as_ref(), ticket_ownership.user.as_ref()],
+        bump,
+    )]
+    pub loyalty: Account<'info, LoyaltyRecord>,
+
     /// The administrator of the event. Their signature is required to authorize this action.
+    /// Pays for any additional rent needed by the updated NFT metadata and the loyalty record.
     #[account(mut, address = event.admin @ EventError::AuthorityMismatch)]
     pub admin: Signer<'info>,
+
+    /// The event's ticket registry, where the check-in is recorded.
+    /// Required when the event tracks ticket statuses in a registry.
+    #[account(
+        mut,
+        seeds = [TICKET_REGISTRY_SEED, event.key().as_ref()],
+        bump = ticket_registry.load()?.bump,
+    )]
+    pub ticket_registry: Option<AccountLoader<'info, TicketRegistry>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
 }
 
 /// Handles the logic for marking a ticket as used.
 ///
 /// This instruction can only be called by the event administrator.
-/// It prevents a ticket from being used more than once.
+/// It prevents a ticket from being used more than once, updates the
+/// `status` and `checked_in_at` fields of the ticket NFT metadata and counts the
+/// event in the `LoyaltyRecord` of the wallet holding the ticket.
 ///
 /// # Arguments
 ///
@@ -46,12 +92,68 @@ pub struct UseTicket<'info> {
 /// An empty `Result` indicating success or failure.
 pub fn use_ticket_handler(ctx: Context<UseTicket>, _event_id: u64, _ticket_id: u64) -> Result<()> {
     let clock = Clock::get()?;
-    require!(
-        ctx.accounts.ticket.valid_until >= clock.unix_timestamp,
-        EventError::TicketExpired
-    );
+    ctx.accounts
+        .event
+        .require_status(clock.unix_timestamp, EventStatus::ACTIVE)?;
     require!(!ctx.accounts.ticket.used, EventError::TicketAlreadyUsed);
     ctx.accounts.ticket.used = true;
 
+    // Record the check-in in the ticket registry, which may already know about a batch check-in
+    if ctx.accounts.event.has_ticket_registry {
+        let registry_info = ctx
+            .accounts
+            .ticket_registry
+            .as_ref()
+            .ok_or(EventError::TicketRegistryRequired)?
+            .to_account_info();
+        let mut data = registry_info.try_borrow_mut_data()?;
+        let ticket_id = ctx.accounts.ticket.id;
+        require!(
+            TicketRegistry::flags(&data, ticket_id)? & TicketRegistry::USED == 0,
+            EventError::TicketAlreadyUsed
+        );
+        TicketRegistry::set_flags(&mut data, ticket_id, TicketRegistry::USED)?;
+    }
+
+    let event_pubkey = ctx.accounts.event.key();
+
+    // Count the event towards the attendance of the ticket's user, skipping further check-ins
+    // at the event they last attended
+    let loyalty = &mut ctx.accounts.loyalty;
+    if loyalty.organizer == Pubkey::default() {
+        loyalty.organizer = ctx.accounts.event.admin;
+        loyalty.wallet = ctx.accounts.ticket_ownership.user;
+        loyalty.bump = ctx.bumps.loyalty;
+    }
+    if loyalty.last_event != event_pubkey {
+        loyalty.events_attended = loyalty
+            .events_attended
+            .checked_add(1)
+            .ok_or(EventError::NumericOverflow)?;
+        loyalty.last_event = event_pubkey;
+    }
+    loyalty.last_attended_at = clock.unix_timestamp;
+
+    let ticket_id_bytes = ctx.accounts.ticket.id.to_be_bytes();
+    let ticket_signer_seeds = &[
+        TICKET_SEED,
+        event_pubkey.as_ref(),
+        &ticket_id_bytes,
+        &[ctx.accounts.ticket.bump],
+    ];
+
+    update_ticket_metadata(
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.ticket_mint.to_account_info(),
+        &ctx.accounts.ticket.to_account_info(),
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &[&ticket_signer_seeds[..]],
+        &[
+            (METADATA_STATUS_KEY, TICKET_STATUS_USED.to_string()),
+            (METADATA_CHECKED_IN_AT_KEY, clock.unix_timestamp.to_string()),
+        ],
+    )?;
+
     Ok(())
 }
@@ -1,12 +1,12 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    constants::{EVENT_SEED, VAULT_SEED},
+    constants::{DISCRIMINATOR_LENGHT, EVENT_ARCHIVE_SEED, EVENT_SEED, VAULT_SEED},
     errors::EventError,
-    state::{Event, EventVault},
+    state::{Event, EventArchive, EventStatus, EventVault},
 };
 
-/// Contextual accounts required to withdraw funds and close an event.
+/// Contextual accounts required to withdraw funds, close an event and archive it.
 #[derive(Accounts)]
 #[instruction(event_id: u64)]
 pub struct WithdrawFunds<'info> {
@@ -28,6 +28,17 @@ pub struct WithdrawFunds<'info> {
     )]
     pub event_vault: Account<'info, EventVault>,
 
+    /// The archive that replaces the event, so that its outstanding tickets can still
+    /// be verified and cleaned up.
+    #[account(
+        init,
+        payer = admin,
+        space = DISCRIMINATOR_LENGHT + EventArchive::INIT_SPACE,
+        seeds = [EVENT_ARCHIVE_SEED, event.key().as_ref()],
+        bump,
+    )]
+    pub event_archive: Account<'info, EventArchive>,
+
     /// The event administrator.
     #[account(mut, address = event.admin @ EventError::AuthorityMismatch)]
     pub admin: Signer<'info>,
@@ -42,6 +53,11 @@ pub struct WithdrawFunds<'info> {
 
 /// Handles the logic for withdrawing event proceeds and closing the event.
 ///
+/// The event is replaced by a small `EventArchive` recording its ID, admin, times and
+/// final ticket counters.
+///
+/// The event must have ended, or been cancelled with every ticket returned.
+///
 /// # Arguments
 ///
 /// * `ctx` - The context containing all necessary accounts.
@@ -52,11 +68,39 @@ pub struct WithdrawFunds<'info> {
 /// An empty `Result` indicating success or failure.
 pub fn withdraw_funds_handler(ctx: Context<WithdrawFunds>, _event_id: u64) -> Result<()> {
     let clock = Clock::get()?;
+    let event = &ctx.accounts.event;
+    let status = event.require_status(
+        clock.unix_timestamp,
+        &[EventStatus::Ended, EventStatus::Cancelled],
+    )?;
+    // Holders of a cancelled event are refunded from the vault, so it can only be
+    // emptied once every ticket has been returned.
+    if status == EventStatus::Cancelled {
+        require!(
+            event.tickets_sold == event.tickets_returned,
+            EventError::TicketsOutstanding
+        );
+    }
+    // Reservations hold the event's inventory and their buyers' payments until they are
+    // claimed or cancelled, which closes them.
     require!(
-        ctx.accounts.event.end_time < clock.unix_timestamp,
-        EventError::EventNotEnded
+        event.tickets_reserved == 0,
+        EventError::ReservationsOutstanding
     );
 
+    // Archive the event before it is closed
+    let archive = &mut ctx.accounts.event_archive;
+    archive.event = event.key();
+    archive.id = event.id;
+    archive.admin = event.admin;
+    archive.start_time = event.start_time;
+    archive.end_time = event.end_time;
+    archive.total_tickets = event.total_tickets;
+    archive.tickets_sold = event.tickets_sold;
+    archive.tickets_returned = event.tickets_returned;
+    archive.has_ticket_registry = event.has_ticket_registry;
+    archive.bump = ctx.bumps.event_archive;
+
     if let Some(destination) = &ctx.accounts.destination_vault {
         let vault_balance = ctx.accounts.event_vault.to_account_info().lamports();
         if vault_balance > 0 {
@@ -2,9 +2,14 @@ mod constants;
 mod errors;
 mod instructions;
 mod state;
+mod utils;
 
 use anchor_lang::prelude::*;
 use instructions::*;
+use state::{
+    AttestationRequirement, DemandCurve, DutchAuction, EventDetails, EventStatus, InstallmentPlan,
+    LotteryConfig, LoyaltyProgram, PromoCodeConfig, SeatSection, SeatSelection,
+};
 
 use spl_discriminator::discriminator::SplDiscriminate;
 use spl_transfer_hook_interface::instruction::ExecuteInstruction;
@@ -32,6 +37,9 @@ pub mod event_tickets {
     /// * `end_time` - The Unix timestamp for when the event ends.
     /// * `ticket_price` - The price of one ticket in lamports.
     /// * `total_tickets` - The total number of tickets available for sale.
+    /// * `details` - The venue, category and age rating of the event.
+    /// * `draft` - Whether the event is created as a draft, which can be configured privately
+    ///   and must be published with `publish_event` before tickets go on sale.
     pub fn create_event(
         ctx: Context<CreateEvent>,
         name: String,
@@ -42,6 +50,8 @@ pub mod event_tickets {
         end_time: i64,
         ticket_price: u64,
         total_tickets: u64,
+        details: EventDetails,
+        draft: bool,
     ) -> Result<()> {
         create_event_handler(
             ctx,
@@ -53,20 +63,407 @@ pub mod event_tickets {
             end_time,
             ticket_price,
             total_tickets,
+            details,
+            draft,
         )
     }
 
+    /// Updates the name, symbol, description and metadata URI of an event, resizing
+    /// the event account to fit them.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `name` - The new name of the event.
+    /// * `symbol` - The new symbol of the event.
+    /// * `description` - The new description of the event.
+    /// * `metadata_uri` - The new URI pointing to additional metadata about the event.
+    pub fn update_event_info(
+        ctx: Context<UpdateEventInfo>,
+        event_id: u64,
+        name: String,
+        symbol: String,
+        description: String,
+        metadata_uri: String,
+    ) -> Result<()> {
+        update_event_info_handler(ctx, event_id, name, symbol, description, metadata_uri)
+    }
+
+    /// Updates the venue, category and age rating of an event.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `details` - The new details of the event.
+    pub fn update_event_details(
+        ctx: Context<UpdateEventDetails>,
+        event_id: u64,
+        details: EventDetails,
+    ) -> Result<()> {
+        update_event_details_handler(ctx, event_id, details)
+    }
+
+    /// Changes the status of an event on behalf of its organizer.
+    ///
+    /// The organizer can pause and resume ticket sales, and cancel an event that has
+    /// not ended yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `status` - The new status of the event.
+    pub fn update_event_status(
+        ctx: Context<UpdateEventStatus>,
+        event_id: u64,
+        status: EventStatus,
+    ) -> Result<()> {
+        update_event_status_handler(ctx, event_id, status)
+    }
+
+    /// Records that an event has gone live or has ended once its start or end time passes.
+    ///
+    /// This instruction is permissionless.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    pub fn advance_event_status(ctx: Context<AdvanceEventStatus>, event_id: u64) -> Result<()> {
+        advance_event_status_handler(ctx, event_id)
+    }
+
+    /// Publishes a draft event, putting its tickets on sale.
+    ///
+    /// Validates the event, its pricing mode, seat map, lottery and waitlist as their
+    /// configuration instructions do, and fails if the event, its auction or its lottery
+    /// entry window is already over.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    pub fn publish_event(ctx: Context<PublishEvent>, event_id: u64) -> Result<()> {
+        publish_event_handler(ctx, event_id)
+    }
+
+    /// Deletes a draft event and returns the rent of its accounts to the admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    pub fn delete_draft_event(ctx: Context<DeleteDraftEvent>, event_id: u64) -> Result<()> {
+        delete_draft_event_handler(ctx, event_id)
+    }
+
+    /// Creates the seat map of an event, enabling reserved seating.
+    ///
+    /// The seat map must contain exactly `total_tickets` seats and can only be created
+    /// before any ticket is sold.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `sections` - The sections of the venue, each with its rows, seats and optional price.
+    pub fn create_seat_map(
+        ctx: Context<CreateSeatMap>,
+        event_id: u64,
+        sections: Vec<SeatSection>,
+    ) -> Result<()> {
+        create_seat_map_handler(ctx, event_id, sections)
+    }
+
+    /// Sets or clears the attestation buyers must present to buy tickets for an event.
+    ///
+    /// Buyers present the attestation in an Ed25519 program instruction placed before
+    /// the purchase, signed by the trusted issuer over the recipient's wallet, the claim
+    /// and an expiry.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `requirement` - The trusted issuer and required claim, or `None` to sell to anyone.
+    pub fn set_attestation_requirement(
+        ctx: Context<SetAttestationRequirement>,
+        event_id: u64,
+        requirement: Option<AttestationRequirement>,
+    ) -> Result<()> {
+        set_attestation_requirement_handler(ctx, event_id, requirement)
+    }
+
+    /// Sets or clears the gatekeeper that must approve every ticket purchase of an event.
+    ///
+    /// The gatekeeper either co-signs the purchase or signs a short-lived, single-use token
+    /// for the event and the buyer that is included as an Ed25519 instruction before the
+    /// purchase.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `gatekeeper` - The gatekeeper's key, or `None` to let anyone buy directly.
+    pub fn set_gatekeeper(
+        ctx: Context<SetGatekeeper>,
+        event_id: u64,
+        gatekeeper: Option<Pubkey>,
+    ) -> Result<()> {
+        set_gatekeeper_handler(ctx, event_id, gatekeeper)
+    }
+
+    /// Sets or clears the loyalty program of an event.
+    ///
+    /// Wallets that checked in to enough of the admin's earlier events get a discount
+    /// and can buy tickets during a presale.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `program` - The loyalty program, or `None` to treat every buyer the same.
+    pub fn set_loyalty_program(
+        ctx: Context<SetLoyaltyProgram>,
+        event_id: u64,
+        program: Option<LoyaltyProgram>,
+    ) -> Result<()> {
+        set_loyalty_program_handler(ctx, event_id, program)
+    }
+
+    /// Sets or clears the template of the per-ticket metadata URIs of an event.
+    ///
+    /// `{id}` is replaced with the ticket ID and, for reserved seating, `{tier}` with the
+    /// section index and `{seat}` with the seat index, e.g. `https://cdn.example/{tier}/{seat}.json`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `uri_template` - The template, or `None` to give every ticket the event's metadata URI.
+    pub fn set_uri_template(
+        ctx: Context<SetUriTemplate>,
+        event_id: u64,
+        uri_template: Option<String>,
+    ) -> Result<()> {
+        set_uri_template_handler(ctx, event_id, uri_template)
+    }
+
+    /// Switches an event to descending-price (Dutch auction) sales.
+    ///
+    /// The price of each ticket is computed from the clock at purchase time and
+    /// recorded on the ticket. Can only be configured before any ticket is sold.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `auction` - The start price, floor price, decay curve and window of the auction.
+    pub fn configure_auction(
+        ctx: Context<ConfigureAuction>,
+        event_id: u64,
+        auction: DutchAuction,
+    ) -> Result<()> {
+        configure_auction_handler(ctx, event_id, auction)
+    }
+
+    /// Switches an event to demand-based pricing, where the price rises as tickets sell.
+    ///
+    /// Can only be configured before any ticket is sold.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `curve` - The pricing curve evaluated against the tickets in circulation.
+    pub fn configure_demand_pricing(
+        ctx: Context<ConfigureDemandPricing>,
+        event_id: u64,
+        curve: DemandCurve,
+    ) -> Result<()> {
+        configure_demand_pricing_handler(ctx, event_id, curve)
+    }
+
+    /// Creates a ticket lottery for an oversubscribed event.
+    ///
+    /// Fans register during the entry window, the admin draws the winners from the hash of
+    /// a future slot committed to here, and only winners can buy tickets until the claim
+    /// window closes.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `config` - The entry windows, number of winners, deposit, draw slot and optional commitment.
+    pub fn create_lottery(
+        ctx: Context<CreateLottery>,
+        event_id: u64,
+        config: LotteryConfig,
+    ) -> Result<()> {
+        create_lottery_handler(ctx, event_id, config)
+    }
+
+    /// Registers the signer for an event's lottery, paying the entry deposit.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    pub fn enter_lottery(ctx: Context<EnterLottery>, event_id: u64) -> Result<()> {
+        enter_lottery_handler(ctx, event_id)
+    }
+
+    /// Draws the winners of an event's lottery once the entry window and the draw slot
+    /// have passed. Anyone can draw, and a committed secret that is not revealed within
+    /// the reveal window is left out of the seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `secret` - The preimage of the lottery commitment, required while the reveal window is open.
+    pub fn draw_lottery(
+        ctx: Context<DrawLottery>,
+        event_id: u64,
+        secret: Option<[u8; 32]>,
+    ) -> Result<()> {
+        draw_lottery_handler(ctx, event_id, secret)
+    }
+
+    /// Returns the deposit of a losing or unused lottery entry and closes it.
+    ///
+    /// Once the event has been settled, its archive is provided instead of the event.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    pub fn claim_lottery_refund(ctx: Context<ClaimLotteryRefund>, event_id: u64) -> Result<()> {
+        claim_lottery_refund_handler(ctx, event_id)
+    }
+
+    /// Opens a waitlist that receives returned tickets of an event.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `claim_window` - How long, in seconds, a buyer has to claim an offered ticket.
+    pub fn create_waitlist(
+        ctx: Context<CreateWaitlist>,
+        event_id: u64,
+        claim_window: i64,
+    ) -> Result<()> {
+        create_waitlist_handler(ctx, event_id, claim_window)
+    }
+
+    /// Joins the end of an event's waitlist with a prepaid deposit.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `deposit` - The prepaid amount in lamports, which must cover the ticket price.
+    pub fn join_waitlist(ctx: Context<JoinWaitlist>, event_id: u64, deposit: u64) -> Result<()> {
+        join_waitlist_handler(ctx, event_id, deposit)
+    }
+
+    /// Offers a ticket reserved by a return to the head of the waitlist.
+    ///
+    /// This instruction is permissionless.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    pub fn offer_waitlist_slot(ctx: Context<OfferWaitlistSlot>, event_id: u64) -> Result<()> {
+        offer_waitlist_slot_handler(ctx, event_id)
+    }
+
+    /// Expires an unclaimed waitlist offer so the ticket moves to the next entry.
+    ///
+    /// This instruction is permissionless.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `position` - The position of the entry whose offer expired.
+    pub fn expire_waitlist_offer(
+        ctx: Context<ExpireWaitlistOffer>,
+        event_id: u64,
+        position: u64,
+    ) -> Result<()> {
+        expire_waitlist_offer_handler(ctx, event_id, position)
+    }
+
+    /// Returns the deposit of an expired or never-served waitlist entry and closes it.
+    ///
+    /// Once the event has been settled, its archive is provided instead of the event.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `position` - The position of the entry to refund.
+    pub fn refund_waitlist_entry(
+        ctx: Context<RefundWaitlistEntry>,
+        event_id: u64,
+        position: u64,
+    ) -> Result<()> {
+        refund_waitlist_entry_handler(ctx, event_id, position)
+    }
+
+    /// Creates a promo code granting a discount on tickets for an event.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `code_hash` - The SHA-256 hash of the code buyers will enter.
+    /// * `config` - The discount, usage cap, validity window and section restriction.
+    pub fn create_promo_code(
+        ctx: Context<CreatePromoCode>,
+        event_id: u64,
+        code_hash: [u8; 32],
+        config: PromoCodeConfig,
+    ) -> Result<()> {
+        create_promo_code_handler(ctx, event_id, code_hash, config)
+    }
+
+    /// Closes a promo code so it can no longer be redeemed, returning its rent to the admin.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    pub fn close_promo_code(ctx: Context<ClosePromoCode>, event_id: u64) -> Result<()> {
+        close_promo_code_handler(ctx, event_id)
+    }
+
     /// Mints a new ticket NFT for a specific event.
     ///
     /// This instruction verifies that the event is not sold out, transfers the ticket price
-    /// from the buyer to the event vault, and mints a new SPL token to the buyer's wallet.
+    /// from the buyer to the event vault, and mints a new SPL token to the recipient's wallet,
+    /// which can be the buyer's own wallet or a friend's for gifts.
     ///
     /// # Arguments
     ///
     /// * `ctx` - The context containing all necessary accounts.
     /// * `event_id` - The unique ID of the event for which to mint the ticket.
-    pub fn mint_ticket(ctx: Context<MintTicket>, event_id: u64) -> Result<()> {
-        mint_ticket_handler(ctx, event_id)
+    /// * `seat` - The seat to buy, required if the event has reserved seating.
+    /// * `max_price` - The highest price the buyer accepts, for slippage protection.
+    /// * `promo_code` - The promo code to redeem for a discount, if any.
+    pub fn mint_ticket(
+        ctx: Context<MintTicket>,
+        event_id: u64,
+        seat: Option<SeatSelection>,
+        max_price: Option<u64>,
+        promo_code: Option<String>,
+    ) -> Result<()> {
+        mint_ticket_handler(ctx, event_id, seat, max_price, promo_code)
     }
 
     /// Ticket mint transfer hook handler
@@ -81,7 +478,8 @@ pub mod event_tickets {
     /// Marking a ticket as used.
     ///
     /// This instruction can only be called by the event administrator.
-    /// It prevents a ticket from being used more than once.
+    /// It prevents a ticket from being used more than once and records the
+    /// check-in in the ticket NFT metadata.
     ///
     /// # Arguments
     ///
@@ -92,10 +490,58 @@ pub mod event_tickets {
         use_ticket_handler(ctx, event_id, ticket_id)
     }
 
+    /// Creates the ticket registry of an event, a single account tracking whether each
+    /// ticket has been used, returned or closed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    pub fn create_ticket_registry(ctx: Context<CreateTicketRegistry>, event_id: u64) -> Result<()> {
+        create_ticket_registry_handler(ctx, event_id)
+    }
+
+    /// Marks many tickets as used in the event's ticket registry.
+    ///
+    /// This instruction can only be called by the event administrator.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `ticket_ids` - The IDs of the tickets to check in.
+    pub fn batch_use_tickets(
+        ctx: Context<BatchUseTickets>,
+        event_id: u64,
+        ticket_ids: Vec<u64>,
+    ) -> Result<()> {
+        batch_use_tickets_handler(ctx, event_id, ticket_ids)
+    }
+
+    /// Chooses whether the rent of a ticket's accounts is refunded to its current holder
+    /// instead of the wallet that paid it when they are closed.
+    ///
+    /// Only the wallet that paid the rent can call this instruction.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `ticket_id` - The ID of the ticket, used for PDA validation.
+    /// * `enabled` - Whether the rent goes to the holder rather than the payer.
+    pub fn set_rent_refund_to_holder(
+        ctx: Context<SetRentRefundToHolder>,
+        event_id: u64,
+        ticket_id: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        set_rent_refund_to_holder_handler(ctx, event_id, ticket_id, enabled)
+    }
+
     /// Close an expired ticket account.
     ///
     /// This instruction allows the event admin to clean up by closing ticket PDA accounts
-    /// after an event has concluded, reclaiming the rent.
+    /// after an event has concluded, returning the rent to the ticket's rent recipient.
     ///
     /// # Arguments
     ///
@@ -110,8 +556,46 @@ pub mod event_tickets {
         close_expired_ticket_handler(ctx, event_id, ticket_id)
     }
 
+    /// Cleans up an expired ticket once the grace period after its event has passed.
+    ///
+    /// This instruction is permissionless. The caller receives a fixed bounty from
+    /// the reclaimed rent, and the rest goes back to the ticket's rent recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `ticket_id` - The ID of the ticket, used for PDA validation.
+    pub fn cleanup_expired_ticket(
+        ctx: Context<CleanupExpiredTicket>,
+        event_id: u64,
+        ticket_id: u64,
+    ) -> Result<()> {
+        cleanup_expired_ticket_handler(ctx, event_id, ticket_id)
+    }
+
+    /// Closes many expired tickets at once, returning their rent to each ticket's rent recipient.
+    ///
+    /// The tickets are passed through the remaining accounts, in groups of
+    /// (ticket, mint, ownership, token account, rent recipient). Tickets that were already returned
+    /// or closed are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    pub fn batch_close_expired_tickets<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchCloseExpiredTickets<'info>>,
+        event_id: u64,
+    ) -> Result<()> {
+        batch_close_expired_tickets_handler(ctx, event_id)
+    }
+
     /// Withdrawing event proceeds and closing the event.
     ///
+    /// The event must have ended, or been cancelled with every ticket returned. It is
+    /// replaced by an `EventArchive`, which the ticket cleanup instructions accept in its place.
+    ///
     /// # Arguments
     ///
     /// * `ctx` - The context containing all necessary accounts.
@@ -120,10 +604,90 @@ pub mod event_tickets {
         withdraw_funds_handler(ctx, event_id)
     }
 
+    /// Lets buyers reserve tickets with a deposit and pay the rest in installments.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `plan` - The deposit and installment schedule, or `None` to stop taking reservations.
+    pub fn configure_installments(
+        ctx: Context<ConfigureInstallments>,
+        event_id: u64,
+        plan: Option<InstallmentPlan>,
+    ) -> Result<()> {
+        configure_installments_handler(ctx, event_id, plan)
+    }
+
+    /// Reserves a ticket with a deposit, holding it until it is fully paid and claimed.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    pub fn reserve_ticket(ctx: Context<ReserveTicket>, event_id: u64) -> Result<()> {
+        reserve_ticket_handler(ctx, event_id)
+    }
+
+    /// Pays the next installment of a reservation.
+    ///
+    /// Once the reservation is fully paid, the ticket NFT is issued by `mint_ticket`
+    /// with the reservation.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    pub fn pay_installment(ctx: Context<PayInstallment>, event_id: u64) -> Result<()> {
+        pay_installment_handler(ctx, event_id)
+    }
+
+    /// Cancels a reservation that missed an installment, forfeiting its deposit to the
+    /// vault, or a reservation of an event that was cancelled or ended, with a full refund.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    pub fn cancel_reservation(ctx: Context<CancelReservation>, event_id: u64) -> Result<()> {
+        cancel_reservation_handler(ctx, event_id)
+    }
+
+    /// Lends a ticket to a borrower until a deadline, keeping its ownership with the lender.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `ticket_id` - The ID of the ticket being lent.
+    /// * `lent_until` - The Unix timestamp after which the ticket can be reclaimed.
+    pub fn lend_ticket(
+        ctx: Context<LendTicket>,
+        event_id: u64,
+        ticket_id: u64,
+        lent_until: i64,
+    ) -> Result<()> {
+        lend_ticket_handler(ctx, event_id, ticket_id, lent_until)
+    }
+
+    /// Brings a lent ticket back to its owner.
+    ///
+    /// The borrower can return it at any time, and anyone can reclaim it without the
+    /// borrower's signature once the loan deadline has passed or the ticket has been used.
+    /// A ticket the borrower burned is minted to its owner again.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `ticket_id` - The ID of the ticket being reclaimed.
+    pub fn reclaim_ticket(ctx: Context<ReclaimTicket>, ticket_id: u64) -> Result<()> {
+        reclaim_ticket_handler(ctx, ticket_id)
+    }
+
     /// Allows a buyer to return an unused ticket for a full refund.
     ///
-    /// This instruction validates the ticket, refunds the buyer, burns the NFT,
-    /// and closes the ticket account.
+    /// This instruction validates the ticket, refunds the buyer, burns the NFT and
+    /// closes the ticket, its mint, ownership record and transfer hook accounts.
     ///
     /// # Arguments
     ///
@@ -133,4 +697,93 @@ pub mod event_tickets {
     pub fn return_ticket(ctx: Context<ReturnTicket>, event_id: u64, ticket_id: u64) -> Result<()> {
         return_ticket_handler(ctx, event_id, ticket_id)
     }
+
+    /// Pays the ticket holder the difference between the price paid and the
+    /// final clearing price of a Dutch auction.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `ticket_id` - The ID of the ticket, used for PDA validation.
+    pub fn claim_auction_rebate(
+        ctx: Context<ClaimAuctionRebate>,
+        event_id: u64,
+        ticket_id: u64,
+    ) -> Result<()> {
+        claim_auction_rebate_handler(ctx, event_id, ticket_id)
+    }
+
+    /// Switches an event to lite tickets, recorded in a compact registry instead of NFTs.
+    ///
+    /// The registry account must be created and assigned to this program beforehand,
+    /// in the same transaction, with room for at least `total_tickets` entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    pub fn create_lite_registry(ctx: Context<CreateLiteRegistry>, event_id: u64) -> Result<()> {
+        create_lite_registry_handler(ctx, event_id)
+    }
+
+    /// Buys a lite ticket, recorded in the event's registry for the recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `max_price` - The highest price the buyer accepts, for slippage protection.
+    pub fn mint_lite_ticket(
+        ctx: Context<MintLiteTicket>,
+        event_id: u64,
+        max_price: Option<u64>,
+    ) -> Result<()> {
+        mint_lite_ticket_handler(ctx, event_id, max_price)
+    }
+
+    /// Transfers a lite ticket from its holder to another wallet.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `ticket_id` - The ID of the ticket in the registry.
+    pub fn transfer_lite_ticket(
+        ctx: Context<TransferLiteTicket>,
+        event_id: u64,
+        ticket_id: u64,
+    ) -> Result<()> {
+        transfer_lite_ticket_handler(ctx, event_id, ticket_id)
+    }
+
+    /// Returns an unused lite ticket for a refund of the price paid.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `ticket_id` - The ID of the ticket in the registry.
+    pub fn return_lite_ticket(
+        ctx: Context<ReturnLiteTicket>,
+        event_id: u64,
+        ticket_id: u64,
+    ) -> Result<()> {
+        return_lite_ticket_handler(ctx, event_id, ticket_id)
+    }
+
+    /// Checks in a lite ticket. Requires the signatures of both the admin and the holder.
+    ///
+    /// # Arguments
+    ///
+    /// * `ctx` - The context containing all necessary accounts.
+    /// * `event_id` - The ID of the event, used for PDA validation.
+    /// * `ticket_id` - The ID of the ticket in the registry.
+    pub fn use_lite_ticket(
+        ctx: Context<UseLiteTicket>,
+        event_id: u64,
+        ticket_id: u64,
+    ) -> Result<()> {
+        use_lite_ticket_handler(ctx, event_id, ticket_id)
+    }
 }
@@ -1,4 +1,13 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::{
+    constants::{
+        DISCRIMINATOR_LENGHT, LOTTERY_DRAW_WINDOW_SLOTS, LOTTERY_REVEAL_WINDOW_SLOTS,
+        MAX_SEAT_SECTIONS,
+    },
+    errors::EventError,
+};
 
 /// The main account representing a single event.
 ///
@@ -25,10 +34,22 @@ pub struct Event {
     /// The URI for the NFT metadata, pointing to a JSON file.
     #[max_len(200)]
     pub metadata_uri: String,
+    /// An optional template for per-ticket metadata URIs, see `Event::ticket_uri`.
+    #[max_len(200)]
+    pub uri_template: Option<String>,
+    /// Structured venue, category and age rating of the event.
+    pub details: EventDetails,
+    /// The attestation buyers must present to buy tickets, if the event restricts sales.
+    pub attestation: Option<AttestationRequirement>,
+    /// The gatekeeper that must approve every purchase, e.g. after a human check.
+    pub gatekeeper: Option<Pubkey>,
     /// The Unix timestamp when the event starts.
     pub start_time: i64,
     /// The Unix timestamp when the event ends.
     pub end_time: i64,
+    /// The lifecycle status of the event as of its last transition. Time-based
+    /// transitions apply even before they are recorded, see `Event::status_at`.
+    pub status: EventStatus,
     /// The price of a single ticket in lamports.
     pub ticket_price: u64,
     /// The total number of tickets available for this event.
@@ -37,10 +58,570 @@ pub struct Event {
     pub tickets_sold: u64,
     /// The number of tickets that have been returned by buyers.
     pub tickets_returned: u64,
+    /// The number of tickets held by reservations that have not been claimed yet.
+    pub tickets_reserved: u64,
+    /// How tickets can be paid in installments, if the event sells them that way.
+    pub installment_plan: Option<InstallmentPlan>,
+    /// The perks returning attendees of the organizer get, if the event rewards loyalty.
+    pub loyalty: Option<LoyaltyProgram>,
+    /// Whether tickets for this event are sold for specific seats of a `SeatMap`.
+    pub has_seat_map: bool,
+    /// Whether tickets are first offered to the winners of a `Lottery`.
+    pub has_lottery: bool,
+    /// Whether returned tickets are offered to the buyers queued on a `Waitlist`.
+    pub has_waitlist: bool,
+    /// The `LiteRegistry` holding this event's tickets, if tickets are issued without NFTs.
+    pub lite_registry: Option<Pubkey>,
+    /// Whether the status of every ticket is tracked in the event's `TicketRegistry`.
+    pub has_ticket_registry: bool,
+    /// How the price of a ticket is determined.
+    pub pricing: PricingMode,
+    /// The price of the most recent auction sale, used as the final clearing price.
+    pub clearing_price: u64,
     /// The bump seed for the event PDA.
     pub bump: u8,
 }
 
+impl Event {
+    /// Returns the space needed to store an event with the given strings, excluding
+    /// the discriminator.
+    ///
+    /// Strings only take the bytes they use, while every other field is sized for its
+    /// largest value, so the account only has to be resized when a string changes.
+    pub fn space_for(
+        name: &str,
+        symbol: &str,
+        description: &str,
+        metadata_uri: &str,
+        uri_template: Option<&str>,
+        details: &EventDetails,
+    ) -> usize {
+        // Saturates so oversized strings are left to the length checks of the caller
+        let unused = 100usize.saturating_sub(name.len())
+            + 10usize.saturating_sub(symbol.len())
+            + 500usize.saturating_sub(description.len())
+            + 200usize.saturating_sub(metadata_uri.len())
+            + 200usize.saturating_sub(uri_template.map_or(0, str::len))
+            + 64usize.saturating_sub(details.venue.len())
+            + 12usize.saturating_sub(details.geohash.len());
+
+        Self::INIT_SPACE - unused
+    }
+
+    /// Returns the space needed to store this event, excluding the discriminator.
+    pub fn space(&self) -> usize {
+        Self::space_for(
+            &self.name,
+            &self.symbol,
+            &self.description,
+            &self.metadata_uri,
+            self.uri_template.as_deref(),
+            &self.details,
+        )
+    }
+
+    /// Returns the status of the event at `now`, applying the time-based transitions
+    /// that have not been recorded yet: an event on sale goes live at `start_time`,
+    /// and an event on sale, closed to sales or live ends after `end_time`.
+    pub fn status_at(&self, now: i64) -> EventStatus {
+        match self.status {
+            EventStatus::OnSale | EventStatus::SalesClosed | EventStatus::Live
+                if now > self.end_time =>
+            {
+                EventStatus::Ended
+            }
+            EventStatus::OnSale if now >= self.start_time => EventStatus::Live,
+            status => status,
+        }
+    }
+
+    /// Returns the status of the event at `now`, failing with an error describing
+    /// that status if it is not one of `allowed`.
+    pub fn require_status(&self, now: i64, allowed: &[EventStatus]) -> Result<EventStatus> {
+        let status = self.status_at(now);
+        if allowed.contains(&status) {
+            return Ok(status);
+        }
+
+        Err(match status {
+            EventStatus::Draft => EventError::EventNotPublished,
+            EventStatus::SalesClosed if allowed.contains(&EventStatus::OnSale) => {
+                EventError::SalesClosed
+            }
+            EventStatus::OnSale | EventStatus::SalesClosed | EventStatus::Live => {
+                EventError::EventNotEnded
+            }
+            EventStatus::Ended => EventError::EventEnded,
+            EventStatus::Cancelled => EventError::EventCancelled,
+            EventStatus::Settled => EventError::EventSettled,
+        }
+        .into())
+    }
+
+    /// Returns `price` with the loyalty discount the holder of `record` is entitled to.
+    ///
+    /// Fails during the loyalty presale unless `record` qualifies. `record` must be the
+    /// `LoyaltyRecord` of the wallet getting the ticket with this event's admin.
+    pub fn loyalty_price(
+        &self,
+        record: Option<&LoyaltyRecord>,
+        price: u64,
+        now: i64,
+    ) -> Result<u64> {
+        let Some(program) = &self.loyalty else {
+            return Ok(price);
+        };
+        let qualifies = record.is_some_and(|record| record.events_attended >= program.min_events);
+
+        if program
+            .presale_end
+            .is_some_and(|presale_end| now < presale_end)
+        {
+            require!(qualifies, EventError::LoyaltyPresaleOnly);
+        }
+        match program.discount {
+            Some(discount) if qualifies => Ok(discount.apply(price)),
+            _ => Ok(price),
+        }
+    }
+
+    /// Returns the metadata URI of ticket `ticket_id`.
+    ///
+    /// Without a `uri_template`, every ticket shares the event's `metadata_uri`. Otherwise
+    /// `{id}` is replaced with the ticket ID and, for reserved seats given as
+    /// `(section, seat_index)`, `{tier}` with the section index and `{seat}` with the
+    /// index of the seat in the seat map.
+    pub fn ticket_uri(&self, ticket_id: u64, seat: Option<(u8, u32)>) -> Result<String> {
+        let Some(template) = &self.uri_template else {
+            return Ok(self.metadata_uri.clone());
+        };
+
+        let mut uri = template.replace("{id}", &ticket_id.to_string());
+        if let Some((section, seat_index)) = seat {
+            uri = uri
+                .replace("{tier}", &section.to_string())
+                .replace("{seat}", &seat_index.to_string());
+        }
+        require!(uri.len() <= 200, EventError::UriTooLong);
+
+        Ok(uri)
+    }
+
+    /// Returns the price of the next ticket at `now`, given the `base_price` of the
+    /// selected seat (or the event's `ticket_price` for general admission).
+    pub fn current_price(&self, base_price: u64, now: i64) -> Result<u64> {
+        match &self.pricing {
+            PricingMode::Fixed => Ok(base_price),
+            PricingMode::DutchAuction(auction) => auction.price_at(now),
+            PricingMode::Demand(curve) => curve.price_for(
+                base_price,
+                self.tickets_sold - self.tickets_returned,
+                self.total_tickets,
+            ),
+        }
+    }
+
+    /// Validates the settings `create_event` checks and the event's pricing mode, and
+    /// that neither the event nor its auction is over at `now`.
+    pub fn validate_setup(&self, now: i64) -> Result<()> {
+        require!(
+            self.end_time > self.start_time,
+            EventError::InvalidEventTime
+        );
+        require!(self.end_time > now, EventError::EventEnded);
+        require!(self.total_tickets > 0, EventError::InvalidTicketCount);
+        require!(self.ticket_price > 0, EventError::InvalidTicketPrice);
+        self.details.validate()?;
+
+        match &self.pricing {
+            PricingMode::Fixed => {}
+            PricingMode::DutchAuction(auction) => {
+                auction.validate(self.end_time)?;
+                require!(auction.end_time > now, EventError::InvalidAuctionTime);
+            }
+            PricingMode::Demand(curve) => curve.validate()?,
+        }
+
+        Ok(())
+    }
+}
+
+/// Structured information about an event that clients can filter on.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct EventDetails {
+    /// The name of the venue.
+    #[max_len(64)]
+    pub venue: String,
+    /// The location of the venue as a geohash of up to 12 characters, empty if unknown.
+    #[max_len(12)]
+    pub geohash: String,
+    /// The category of the event.
+    pub category: EventCategory,
+    /// The minimum age of attendees, 0 for all ages.
+    pub min_age: u8,
+    /// The offset of the venue's local time from UTC, in minutes.
+    pub utc_offset_minutes: i16,
+}
+
+impl EventDetails {
+    /// Validates the lengths and ranges of the details.
+    pub fn validate(&self) -> Result<()> {
+        require!(self.venue.len() <= 64, EventError::VenueTooLong);
+        require!(
+            self.geohash.len() <= 12
+                && self
+                    .geohash
+                    .bytes()
+                    .all(|c| b"0123456789bcdefghjkmnpqrstuvwxyz".contains(&c)),
+            EventError::InvalidGeohash
+        );
+        require!(self.min_age <= 99, EventError::InvalidMinimumAge);
+        // Time zones range from UTC-12:00 to UTC+14:00 in steps of 15 minutes
+        require!(
+            (-720..=840).contains(&self.utc_offset_minutes) && self.utc_offset_minutes % 15 == 0,
+            EventError::InvalidUtcOffset
+        );
+
+        Ok(())
+    }
+}
+
+/// A claim about a wallet that an attestation issuer vouches for.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AttestationClaim {
+    /// The owner of the wallet passed the issuer's identity checks.
+    Kyc,
+    /// The owner of the wallet is at least the given age.
+    MinimumAge(u8),
+}
+
+impl AttestationClaim {
+    /// Returns whether an attestation of this claim satisfies the `required` claim.
+    pub fn satisfies(&self, required: &AttestationClaim) -> bool {
+        match (self, required) {
+            (AttestationClaim::Kyc, AttestationClaim::Kyc) => true,
+            (AttestationClaim::MinimumAge(age), AttestationClaim::MinimumAge(required_age)) => {
+                age >= required_age
+            }
+            _ => false,
+        }
+    }
+}
+
+/// The attestation a buyer must present to buy tickets for an event.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct AttestationRequirement {
+    /// The key of the issuer whose attestations the event trusts.
+    pub issuer: Pubkey,
+    /// The claim the attestation must make about the buyer.
+    pub claim: AttestationClaim,
+}
+
+/// The message an issuer signs to attest a claim about a wallet.
+///
+/// Buyers present it in an Ed25519 program instruction placed before the purchase
+/// in the same transaction, serialized as the wallet, the Borsh-encoded claim and
+/// the expiry as a little-endian `i64`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct AttestationPayload {
+    /// The wallet the claim is about.
+    pub wallet: Pubkey,
+    /// The attested claim.
+    pub claim: AttestationClaim,
+    /// The Unix timestamp after which the attestation is no longer valid.
+    pub expiry: i64,
+}
+
+/// The message a gatekeeper signs to approve a purchase without co-signing it.
+///
+/// Buyers present it in an Ed25519 program instruction placed before the purchase
+/// in the same transaction, serialized as the event's public key, the buyer's wallet
+/// and the slot as a little-endian `u64`. Each token buys once, see `GatekeeperPass`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct GatekeeperToken {
+    /// The public key of the `Event` the purchase is approved for.
+    pub event: Pubkey,
+    /// The wallet allowed to buy.
+    pub buyer: Pubkey,
+    /// The slot the token was issued at. It expires `GATEKEEPER_TOKEN_MAX_AGE_SLOTS` later.
+    pub slot: u64,
+}
+
+/// The gatekeeper tokens a buyer has used at an event, so that none is used twice.
+#[account]
+#[derive(InitSpace)]
+pub struct GatekeeperPass {
+    /// The public key of the `Event` the tokens were issued for.
+    pub event: Pubkey,
+    /// The wallet the tokens were issued to.
+    pub buyer: Pubkey,
+    /// The slot of the most recent token used. Only tokens issued later are accepted.
+    pub last_slot: u64,
+    /// The bump seed for the gatekeeper pass PDA.
+    pub bump: u8,
+}
+
+impl GatekeeperPass {
+    /// Records the use of `token`, rejecting it unless it was issued after the last
+    /// token used.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - The gatekeeper token approving the purchase.
+    /// * `bump` - The bump seed of the pass, stored when the pass is first used.
+    ///
+    /// # Returns
+    ///
+    /// An empty `Result`, or `GatekeeperTokenUsed` if the token was used already.
+    pub fn consume(&mut self, token: &GatekeeperToken, bump: u8) -> Result<()> {
+        if self.buyer == Pubkey::default() {
+            self.event = token.event;
+            self.buyer = token.buyer;
+            self.bump = bump;
+        } else {
+            require!(token.slot > self.last_slot, EventError::GatekeeperTokenUsed);
+        }
+        self.last_slot = token.slot;
+
+        Ok(())
+    }
+}
+
+/// The category of an event.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum EventCategory {
+    /// Concerts and club nights.
+    Music,
+    /// Matches, races and other sporting events.
+    Sports,
+    /// Plays, musicals, opera and dance.
+    Theater,
+    /// Stand-up and other comedy shows.
+    Comedy,
+    /// Conferences, meetups and workshops.
+    Conference,
+    /// Multi-day festivals.
+    Festival,
+    /// Events aimed at children and families.
+    Family,
+    /// Anything else.
+    Other,
+}
+
+impl EventCategory {
+    /// Returns the name of the category as written to the ticket NFT metadata.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventCategory::Music => "music",
+            EventCategory::Sports => "sports",
+            EventCategory::Theater => "theater",
+            EventCategory::Comedy => "comedy",
+            EventCategory::Conference => "conference",
+            EventCategory::Festival => "festival",
+            EventCategory::Family => "family",
+            EventCategory::Other => "other",
+        }
+    }
+}
+
+/// The lifecycle of an event.
+///
+/// ```text
+/// Draft -> OnSale -> Live -> Ended -> Settled
+///            |  ^      |
+///            v  |      |
+///        SalesClosed --+
+/// ```
+///
+/// A published event can also move to `Cancelled` until it has ended.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum EventStatus {
+    /// The event is being prepared and is not visible to buyers.
+    Draft,
+    /// Tickets are on sale and the event has not started yet.
+    OnSale,
+    /// The organizer has paused ticket sales. Issued tickets remain valid.
+    SalesClosed,
+    /// The event has started. Tickets remain on sale until it ends.
+    Live,
+    /// The event is over. Unsold inventory and expired tickets can be cleaned up.
+    Ended,
+    /// The organizer has called off the event. Holders can return their tickets.
+    Cancelled,
+    /// The proceeds of the event have been withdrawn. The `Event` account is closed
+    /// and replaced by its `EventArchive`.
+    Settled,
+}
+
+impl EventStatus {
+    /// Statuses in which tickets can be bought.
+    pub const SELLING: &'static [EventStatus] = &[EventStatus::OnSale, EventStatus::Live];
+    /// Statuses in which issued tickets can be used, returned or transferred.
+    pub const ACTIVE: &'static [EventStatus] = &[
+        EventStatus::OnSale,
+        EventStatus::SalesClosed,
+        EventStatus::Live,
+    ];
+    /// Statuses in which the organizer can still change how the event is sold.
+    pub const CONFIGURABLE: &'static [EventStatus] = &[
+        EventStatus::Draft,
+        EventStatus::OnSale,
+        EventStatus::SalesClosed,
+        EventStatus::Live,
+    ];
+
+    /// Whether the organizer can move an event from this status to `next`.
+    ///
+    /// Time-based transitions (`Live`, `Ended`) cannot be made by the organizer,
+    /// they are recorded with `advance_event_status`. Drafts leave the `Draft` status
+    /// through `publish_event`, which validates their configuration first, or are
+    /// deleted with `delete_draft_event`.
+    pub fn admin_can_transition_to(self, next: EventStatus) -> bool {
+        matches!(
+            (self, next),
+            (
+                EventStatus::OnSale | EventStatus::Live,
+                EventStatus::SalesClosed
+            ) | (EventStatus::SalesClosed, EventStatus::OnSale)
+                | (
+                    EventStatus::OnSale | EventStatus::SalesClosed | EventStatus::Live,
+                    EventStatus::Cancelled
+                )
+        )
+    }
+}
+
+/// The pricing strategy of an event.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub enum PricingMode {
+    /// Every ticket costs the event's `ticket_price` (or its seat section price).
+    Fixed,
+    /// The price of every ticket decreases over time, from a start price to a floor price.
+    DutchAuction(DutchAuction),
+    /// The price of every ticket increases with the number of tickets in circulation.
+    Demand(DemandCurve),
+}
+
+/// How the price of a ticket rises as an event fills up.
+///
+/// Tickets in circulation are `tickets_sold - tickets_returned`, so returns lower the price again.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum DemandCurve {
+    /// The price rises by `increase_bps` of the base price for every `step_bps`
+    /// of `total_tickets` in circulation, e.g. +5% per 10% sold.
+    Stepped { step_bps: u16, increase_bps: u16 },
+    /// The price rises by `increment` lamports for every ticket in circulation.
+    Linear { increment: u64 },
+}
+
+impl DemandCurve {
+    /// Validates that the curve raises the price.
+    pub fn validate(&self) -> Result<()> {
+        match *self {
+            DemandCurve::Stepped {
+                step_bps,
+                increase_bps,
+            } => require!(
+                step_bps > 0 && step_bps <= 10_000 && increase_bps > 0,
+                EventError::InvalidDemandCurve
+            ),
+            DemandCurve::Linear { increment } => {
+                require!(increment > 0, EventError::InvalidDemandCurve)
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the price of the next ticket when `circulating` out of `total_tickets`
+    /// tickets are held by buyers.
+    pub fn price_for(&self, base_price: u64, circulating: u64, total_tickets: u64) -> Result<u64> {
+        let premium = match *self {
+            DemandCurve::Stepped {
+                step_bps,
+                increase_bps,
+            } => {
+                let sold_bps = circulating as u128 * 10_000 / total_tickets as u128;
+                let steps = sold_bps / step_bps as u128;
+                base_price as u128 * increase_bps as u128 * steps / 10_000
+            }
+            DemandCurve::Linear { increment } => increment as u128 * circulating as u128,
+        };
+
+        u64::try_from(base_price as u128 + premium).map_err(|_| error!(EventError::NumericOverflow))
+    }
+}
+
+/// How the price of a Dutch auction decreases over the auction window.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum DecayCurve {
+    /// The price decreases linearly from the start price to the floor price.
+    Linear,
+    /// The distance to the floor price halves every `half_life` seconds.
+    Exponential { half_life: i64 },
+}
+
+/// Configuration of a descending-price (Dutch) auction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct DutchAuction {
+    /// The price of a ticket when the auction starts.
+    pub start_price: u64,
+    /// The lowest price the auction can reach.
+    pub floor_price: u64,
+    /// The Unix timestamp when the auction starts.
+    pub start_time: i64,
+    /// The Unix timestamp when the auction reaches the floor price.
+    pub end_time: i64,
+    /// How the price decreases between `start_time` and `end_time`.
+    pub curve: DecayCurve,
+    /// Whether buyers can claim back the difference to the final clearing price.
+    pub rebate_to_clearing_price: bool,
+}
+
+impl DutchAuction {
+    /// Validates the prices and curve of the auction, and that it ends by `event_end_time`.
+    pub fn validate(&self, event_end_time: i64) -> Result<()> {
+        require!(
+            self.floor_price > 0 && self.start_price > self.floor_price,
+            EventError::InvalidAuctionPrice
+        );
+        require!(
+            self.end_time > self.start_time && self.end_time <= event_end_time,
+            EventError::InvalidAuctionTime
+        );
+        if let DecayCurve::Exponential { half_life } = self.curve {
+            require!(half_life > 0, EventError::InvalidDecayCurve);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the auction price at `now`.
+    pub fn price_at(&self, now: i64) -> Result<u64> {
+        require!(now >= self.start_time, EventError::AuctionNotStarted);
+        if now >= self.end_time {
+            return Ok(self.floor_price);
+        }
+
+        let elapsed = (now - self.start_time) as u128;
+        let range = (self.start_price - self.floor_price) as u128;
+        let discount = match self.curve {
+            DecayCurve::Linear => range * elapsed / (self.end_time - self.start_time) as u128,
+            DecayCurve::Exponential { half_life } => {
+                let halvings = u32::try_from(elapsed / half_life as u128).unwrap_or(u32::MAX);
+                range - range.checked_shr(halvings).unwrap_or(0)
+            }
+        };
+
+        Ok(self.start_price - discount as u64)
+    }
+
+    /// Returns whether the auction has reached its floor price at `now`.
+    pub fn has_ended(&self, now: i64) -> bool {
+        now >= self.end_time
+    }
+}
+
 /// A counter for all events created by a specific admin.
 ///
 /// This account ensures that each event created by an admin has a unique, sequential ID.
@@ -70,14 +651,106 @@ pub struct Ticket {
     pub valid_until: i64,
     /// A flag to indicate whether the ticket has been used or redeemed.
     pub used: bool,
+    /// The amount of lamports paid for the ticket, refunded when it is returned.
+    pub price_paid: u64,
+    /// The wallet that paid the rent of the ticket's accounts.
+    pub payer: Pubkey,
+    /// Whether the rent is refunded to the current holder instead of the payer when the
+    /// ticket's accounts are closed.
+    pub refund_rent_to_holder: bool,
+    /// The reserved seat, if the event has a seat map.
+    pub seat: Option<SeatSelection>,
+    /// A human-readable label of the reserved seat, empty for general admission.
+    #[max_len(32)]
+    pub seat_label: String,
     /// The bump seed for the ticket PDA.
     pub bump: u8,
 }
 
+impl Ticket {
+    /// Returns the wallet that receives the rent of the ticket's accounts when they are
+    /// closed, given the current `holder` of the ticket.
+    pub fn rent_recipient(&self, holder: Pubkey) -> Pubkey {
+        if self.refund_rent_to_holder {
+            holder
+        } else {
+            self.payer
+        }
+    }
+}
+
 /// A PDA account that holds the funds for an event.
 #[account]
 pub struct EventVault {}
 
+/// A tombstone of a settled event.
+///
+/// `withdraw_funds` closes the `Event`, but its tickets may still be outstanding. The
+/// archive keeps what is needed to verify and clean up those tickets, and is found
+/// from a ticket's `event` field.
+#[account]
+#[derive(InitSpace)]
+pub struct EventArchive {
+    /// The public key of the closed `Event` account.
+    pub event: Pubkey,
+    /// The ID of the event.
+    pub id: u64,
+    /// The public key of the administrator of the event.
+    pub admin: Pubkey,
+    /// The Unix timestamp when the event started.
+    pub start_time: i64,
+    /// The Unix timestamp when the event ended.
+    pub end_time: i64,
+    /// The total number of tickets that were available.
+    pub total_tickets: u64,
+    /// The number of tickets sold.
+    pub tickets_sold: u64,
+    /// The number of tickets returned by buyers.
+    pub tickets_returned: u64,
+    /// Whether the status of every ticket is tracked in the event's `TicketRegistry`.
+    pub has_ticket_registry: bool,
+    /// The bump seed for the archive PDA.
+    pub bump: u8,
+}
+
+/// The event a post-event cleanup runs against: an `Event` that has ended, or the
+/// `EventArchive` it left behind once it was settled.
+pub struct EndedEvent {
+    /// The public key of the `Event` account, which may since have been closed.
+    pub key: Pubkey,
+    /// The Unix timestamp when the event ended.
+    pub end_time: i64,
+    /// Whether the status of every ticket is tracked in the event's `TicketRegistry`.
+    pub has_ticket_registry: bool,
+}
+
+impl EndedEvent {
+    /// Resolves the event from whichever of `event` and `event_archive` was provided.
+    /// Exactly one of them must be, and a live event must have ended at `now`.
+    pub fn resolve(
+        event: Option<&Account<Event>>,
+        event_archive: Option<&Account<EventArchive>>,
+        now: i64,
+    ) -> Result<Self> {
+        match (event, event_archive) {
+            (Some(event), None) => {
+                event.require_status(now, &[EventStatus::Ended])?;
+                Ok(Self {
+                    key: event.key(),
+                    end_time: event.end_time,
+                    has_ticket_registry: event.has_ticket_registry,
+                })
+            }
+            (None, Some(archive)) => Ok(Self {
+                key: archive.event,
+                end_time: archive.end_time,
+                has_ticket_registry: archive.has_ticket_registry,
+            }),
+            _ => err!(EventError::InvalidEventAccounts),
+        }
+    }
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct TicketOwnership {
@@ -85,6 +758,620 @@ pub struct TicketOwnership {
     pub ticket: Pubkey,
     /// The public key of the SPL token mint that represents this ticket.
     pub mint: Pubkey,
-    /// The public key of the current owner of the ticket NFT.
+    /// The public key of the current owner of the ticket NFT. It stays with the lender
+    /// while the ticket is lent.
+    pub owner: Pubkey,
+    /// The wallet currently holding the ticket NFT: the owner, or the borrower while the
+    /// ticket is lent.
+    pub user: Pubkey,
+    /// The Unix timestamp until which the ticket is lent to `user`, `None` if it is not lent.
+    pub lent_until: Option<i64>,
+}
+
+/// A block of seats inside a venue, such as "Orchestra" or "Balcony".
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct SeatSection {
+    /// The name of the section, used in seat labels.
+    #[max_len(16)]
+    pub name: String,
+    /// The number of rows in the section.
+    pub rows: u16,
+    /// The number of seats in every row of the section.
+    pub seats_per_row: u16,
+    /// The price of a seat in this section, overriding the event's `ticket_price`.
+    pub price: Option<u64>,
+}
+
+/// A specific seat chosen by a buyer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct SeatSelection {
+    /// The index of the section in the seat map.
+    pub section: u8,
+    /// The zero-based row within the section.
+    pub row: u16,
+    /// The zero-based seat within the row.
+    pub seat: u16,
+}
+
+/// The seating layout of an event and the seats that have already been sold.
+#[account]
+pub struct SeatMap {
+    /// The public key of the `Event` this seat map belongs to.
+    pub event: Pubkey,
+    /// The sections of the venue, in bitmap order.
+    pub sections: Vec<SeatSection>,
+    /// The total number of seats across all sections.
+    pub total_seats: u32,
+    /// A bitmap with one bit per seat, set when the seat is sold.
+    pub sold: Vec<u8>,
+    /// The bump seed for the seat map PDA.
+    pub bump: u8,
+}
+
+impl SeatMap {
+    /// Validates `sections` and that they seat exactly `total_tickets`.
+    ///
+    /// # Returns
+    ///
+    /// The total number of seats across all sections.
+    pub fn validate_sections(sections: &[SeatSection], total_tickets: u64) -> Result<u64> {
+        require!(
+            !sections.is_empty() && sections.len() <= MAX_SEAT_SECTIONS as usize,
+            EventError::InvalidSectionCount
+        );
+
+        let mut total_seats: u64 = 0;
+        for section in sections {
+            require!(
+                !section.name.is_empty() && section.name.len() <= 16,
+                EventError::InvalidSectionName
+            );
+            require!(
+                section.rows > 0 && section.seats_per_row > 0,
+                EventError::InvalidSectionSize
+            );
+            if let Some(price) = section.price {
+                require!(price > 0, EventError::InvalidTicketPrice);
+            }
+            total_seats += section.rows as u64 * section.seats_per_row as u64;
+        }
+        require!(total_seats == total_tickets, EventError::SeatCountMismatch);
+
+        Ok(total_seats)
+    }
+
+    /// Returns the account space (without discriminator) needed for `sections`.
+    pub fn space(sections: &[SeatSection], total_seats: u32) -> usize {
+        32 + 4
+            + sections.len() * SeatSection::INIT_SPACE
+            + 4
+            + 4
+            + (total_seats as usize).div_ceil(8)
+            + 1
+    }
+
+    /// Returns the position of `selection` in the sold bitmap.
+    pub fn seat_index(&self, selection: &SeatSelection) -> Result<u32> {
+        let section = self
+            .sections
+            .get(selection.section as usize)
+            .ok_or(EventError::InvalidSeat)?;
+        require!(
+            selection.row < section.rows && selection.seat < section.seats_per_row,
+            EventError::InvalidSeat
+        );
+
+        let offset: u32 = self.sections[..selection.section as usize]
+            .iter()
+            .map(|s| s.rows as u32 * s.seats_per_row as u32)
+            .sum();
+
+        Ok(offset + selection.row as u32 * section.seats_per_row as u32 + selection.seat as u32)
+    }
+
+    /// Returns whether the seat at `index` has been sold.
+    pub fn is_sold(&self, index: u32) -> bool {
+        self.sold[(index / 8) as usize] & (1 << (index % 8)) != 0
+    }
+
+    /// Marks the seat at `index` as sold or free.
+    pub fn set_sold(&mut self, index: u32, sold: bool) {
+        let byte = &mut self.sold[(index / 8) as usize];
+        if sold {
+            *byte |= 1 << (index % 8);
+        } else {
+            *byte &= !(1 << (index % 8));
+        }
+    }
+
+    /// Returns the section price for `selection`, or `default_price` if the section has none.
+    pub fn seat_price(&self, selection: &SeatSelection, default_price: u64) -> u64 {
+        self.sections[selection.section as usize]
+            .price
+            .unwrap_or(default_price)
+    }
+
+    /// Returns a human-readable label for `selection`, e.g. "Balcony R3 S12".
+    pub fn seat_label(&self, selection: &SeatSelection) -> String {
+        format!(
+            "{} R{} S{}",
+            self.sections[selection.section as usize].name,
+            selection.row + 1,
+            selection.seat + 1
+        )
+    }
+}
+
+/// Configuration of a ticket lottery, provided by the admin when creating it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct LotteryConfig {
+    /// The Unix timestamp when fans can start registering.
+    pub entry_start: i64,
+    /// The Unix timestamp when registration closes and the draw can happen.
+    pub entry_end: i64,
+    /// The Unix timestamp until which only winners can buy tickets.
+    pub claim_end: i64,
+    /// The number of winners to draw.
+    pub winners: u64,
+    /// The refundable deposit, in lamports, paid with every entry.
+    pub deposit: u64,
+    /// The future slot whose hash seeds the draw. Entries close at this slot, and the draw
+    /// must happen within `LOTTERY_DRAW_WINDOW_SLOTS` after it.
+    pub draw_slot: u64,
+    /// The hash of a secret the admin reveals during the draw, if commit-reveal is used. It
+    /// must be revealed within `LOTTERY_REVEAL_WINDOW_SLOTS` after the draw slot.
+    pub commitment: Option<[u8; 32]>,
+}
+
+impl LotteryConfig {
+    /// Validates the windows and winners of the lottery for `event`, and that the draw
+    /// slot is after `slot`.
+    pub fn validate(&self, event: &Event, slot: u64) -> Result<()> {
+        require!(
+            self.entry_start < self.entry_end
+                && self.entry_end < self.claim_end
+                && self.claim_end <= event.end_time,
+            EventError::InvalidLotteryTime
+        );
+        require!(self.draw_slot > slot, EventError::InvalidLotteryDrawSlot);
+        require!(
+            self.winners > 0 && self.winners <= event.total_tickets,
+            EventError::InvalidWinnerCount
+        );
+
+        Ok(())
+    }
+}
+
+/// A lottery that decides who can buy tickets for an oversubscribed event.
+#[account]
+#[derive(InitSpace)]
+pub struct Lottery {
+    /// The public key of the `Event` this lottery belongs to.
+    pub event: Pubkey,
+    /// The entry windows, number of winners, deposit and commitment of the lottery.
+    pub config: LotteryConfig,
+    /// The number of registered entries.
+    pub entries: u64,
+    /// Whether the winners have been drawn.
+    pub drawn: bool,
+    /// The random seed of the draw, which orders the entries; the first `winners` win.
+    pub seed: [u8; 32],
+    /// The bump seed for the lottery PDA.
+    pub bump: u8,
+}
+
+impl Lottery {
+    /// Returns whether the entry at `index` won the draw.
+    pub fn is_winner(&self, index: u64) -> bool {
+        self.drawn && self.shuffled_position(index) < self.config.winners
+    }
+
+    /// Returns whether the draw can no longer happen at `slot`, because the hash of the
+    /// draw slot is no longer available. Entries of such a lottery can be refunded.
+    pub fn draw_expired(&self, slot: u64) -> bool {
+        !self.drawn
+            && slot
+                > self
+                    .config
+                    .draw_slot
+                    .saturating_add(LOTTERY_DRAW_WINDOW_SLOTS)
+    }
+
+    /// Returns whether the draw at `slot` must reveal the secret of the commitment: while
+    /// the reveal window is open, for lotteries created with one.
+    pub fn reveal_required(&self, slot: u64) -> bool {
+        self.config.commitment.is_some()
+            && slot
+                <= self
+                    .config
+                    .draw_slot
+                    .saturating_add(LOTTERY_REVEAL_WINDOW_SLOTS)
+    }
+
+    /// Returns the position of the entry at `index` in the order of the draw, a random
+    /// permutation of `0..entries` keyed by `seed`.
+    ///
+    /// The permutation is a four-round Feistel network over the smallest power of four
+    /// covering the entries, applied again until the result falls inside `0..entries`.
+    fn shuffled_position(&self, index: u64) -> u64 {
+        let bits = u64::BITS - self.entries.saturating_sub(1).leading_zeros();
+        let half_bits = bits.div_ceil(2).max(1);
+        let mask = (1u64 << half_bits) - 1;
+
+        let mut position = index;
+        loop {
+            let (mut left, mut right) = (position >> half_bits, position & mask);
+            for round in 0u8..4 {
+                let hash = hashv(&[&self.seed, &[round], &right.to_le_bytes()]).to_bytes();
+                let mixed = u64::from_le_bytes(hash[..8].try_into().unwrap()) & mask;
+                (left, right) = (right, left ^ mixed);
+            }
+            position = (left << half_bits) | right;
+            if position < self.entries {
+                return position;
+            }
+        }
+    }
+}
+
+/// A single fan's registration for a lottery. Holds the entry deposit.
+#[account]
+#[derive(InitSpace)]
+pub struct LotteryEntry {
+    /// The public key of the `Lottery` this entry belongs to.
+    pub lottery: Pubkey,
+    /// The wallet that registered and can buy a ticket if the entry wins.
+    pub entrant: Pubkey,
+    /// The sequential index of the entry, used to determine the winners.
+    pub index: u64,
+    /// The bump seed for the lottery entry PDA.
+    pub bump: u8,
+}
+
+/// A queue of buyers waiting for returned tickets of a sold-out event.
+///
+/// Every returned ticket reserves a slot for the queue. Slots are offered to queued
+/// entries in order, and an offer that is not claimed in time moves to the next entry.
+#[account]
+#[derive(InitSpace)]
+pub struct Waitlist {
+    /// The public key of the `Event` this waitlist belongs to.
+    pub event: Pubkey,
+    /// How long, in seconds, an entry has to claim an offered ticket.
+    pub claim_window: i64,
+    /// The position the next joining entry will get.
+    pub next_position: u64,
+    /// The position of the next entry to receive an offer.
+    pub next_offer: u64,
+    /// The number of reserved slots that have not been offered yet.
+    pub open_slots: u64,
+    /// The number of offers that have not been claimed or expired yet.
+    pub active_offers: u64,
+    /// The bump seed for the waitlist PDA.
+    pub bump: u8,
+}
+
+impl Waitlist {
+    /// Returns the number of queued entries that have not received an offer yet.
+    pub fn queued(&self) -> u64 {
+        self.next_position - self.next_offer
+    }
+
+    /// Returns the number of tickets held back from general sale for the queue.
+    pub fn reserved(&self) -> u64 {
+        self.active_offers + self.open_slots
+    }
+}
+
+/// The state of a waitlist entry.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum WaitlistStatus {
+    /// The entry is waiting for a returned ticket.
+    Queued,
+    /// A ticket is reserved for the entry until `offer_expires_at`.
+    Offered,
+    /// The entry did not claim its offer in time and can be refunded.
+    Expired,
+}
+
+/// A buyer's place in a waitlist. Holds the prepaid deposit.
+#[account]
+#[derive(InitSpace)]
+pub struct WaitlistEntry {
+    /// The public key of the `Waitlist` this entry belongs to.
+    pub waitlist: Pubkey,
+    /// The wallet that joined the waitlist and can claim the offered ticket.
+    pub buyer: Pubkey,
+    /// The position of the entry in the queue, used as a seed.
+    pub position: u64,
+    /// The prepaid deposit, in lamports. The ticket price is paid from it.
+    pub deposit: u64,
+    /// The current state of the entry.
+    pub status: WaitlistStatus,
+    /// The Unix timestamp when the current offer expires.
+    pub offer_expires_at: i64,
+    /// The bump seed for the waitlist entry PDA.
+    pub bump: u8,
+}
+
+/// How the tickets of an event can be paid in installments.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct InstallmentPlan {
+    /// The share of the ticket price paid as a deposit when reserving, in basis points.
+    pub deposit_bps: u16,
+    /// The number of installments the rest of the price is split into.
+    pub installments: u8,
+    /// The number of seconds between the reservation and the first installment,
+    /// and between consecutive installments.
+    pub interval: i64,
+}
+
+/// A payment due on a reservation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct Installment {
+    /// The amount due, in lamports.
+    pub amount: u64,
+    /// The Unix timestamp by which the installment must be paid.
+    pub due: i64,
+}
+
+/// A ticket reserved with a deposit and paid in installments. Holds the payments
+/// until the ticket is claimed with `mint_ticket`.
+#[account]
+#[derive(InitSpace)]
+pub struct Reservation {
+    /// The public key of the `Event` the ticket is reserved for.
+    pub event: Pubkey,
+    /// The wallet that reserved the ticket and pays the installments.
+    pub buyer: Pubkey,
+    /// The price of the ticket, locked when it was reserved.
+    pub price: u64,
+    /// The deposit paid when reserving, forfeited if an installment is missed.
+    pub deposit: u64,
+    /// The total amount paid so far, including the deposit.
+    pub paid: u64,
+    /// The installments due after the deposit, in order.
+    #[max_len(12)]
+    pub schedule: Vec<Installment>,
+    /// The bump seed for the reservation PDA.
+    pub bump: u8,
+}
+
+impl Reservation {
+    /// Returns whether the full price has been paid.
+    pub fn is_paid(&self) -> bool {
+        self.paid >= self.price
+    }
+
+    /// Returns the amount still owed on the next installment and its due date,
+    /// or `None` if the reservation is fully paid.
+    pub fn next_installment(&self) -> Option<(u64, i64)> {
+        let mut due_total = self.deposit;
+        for installment in &self.schedule {
+            due_total += installment.amount;
+            if self.paid < due_total {
+                return Some((due_total - self.paid, installment.due));
+            }
+        }
+        None
+    }
+}
+
+/// The perks an event grants to returning attendees of its organizer.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct LoyaltyProgram {
+    /// The number of the organizer's events a wallet must have attended to qualify.
+    pub min_events: u32,
+    /// The discount qualifying wallets get on every ticket, if any.
+    pub discount: Option<Discount>,
+    /// The Unix timestamp until which only qualifying wallets can buy tickets, if the
+    /// event has a presale.
+    pub presale_end: Option<i64>,
+}
+
+/// The attendance of a wallet at the events of an organizer, recorded on check-in.
+#[account]
+#[derive(InitSpace)]
+pub struct LoyaltyRecord {
+    /// The admin of the events attended.
+    pub organizer: Pubkey,
+    /// The wallet that attended the events.
+    pub wallet: Pubkey,
+    /// The number of events the wallet checked in to.
+    pub events_attended: u32,
+    /// The public key of the most recent `Event` the wallet checked in to.
+    pub last_event: Pubkey,
+    /// The Unix timestamp of the most recent check-in.
+    pub last_attended_at: i64,
+    /// The bump seed for the loyalty record PDA.
+    pub bump: u8,
+}
+
+/// A discount on the ticket price, granted by a promo code or a loyalty program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Discount {
+    /// A percentage of the ticket price, in basis points.
+    Percentage { bps: u16 },
+    /// A fixed amount of lamports off the ticket price.
+    Fixed { amount: u64 },
+}
+
+impl Discount {
+    /// Returns `price` with the discount applied.
+    pub fn apply(&self, price: u64) -> u64 {
+        match *self {
+            Discount::Percentage { bps } => price - (price as u128 * bps as u128 / 10_000) as u64,
+            Discount::Fixed { amount } => price.saturating_sub(amount),
+        }
+    }
+}
+
+/// Configuration of a promo code, provided by the admin when creating it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct PromoCodeConfig {
+    /// The discount granted by the code.
+    pub discount: Discount,
+    /// The maximum number of tickets that can be bought with the code.
+    pub max_redemptions: u64,
+    /// The Unix timestamp from which the code can be used.
+    pub valid_from: i64,
+    /// The Unix timestamp after which the code can no longer be used.
+    pub valid_until: i64,
+    /// The seat map sections the code is restricted to; empty for every ticket.
+    #[max_len(32)]
+    pub allowed_sections: Vec<u8>,
+}
+
+/// A discount campaign, redeemable by buyers who know the code.
+///
+/// The code itself is never stored on-chain, only its SHA-256 hash, which is also
+/// used as a seed of the PDA.
+#[account]
+#[derive(InitSpace)]
+pub struct PromoCode {
+    /// The public key of the `Event` this promo code belongs to.
+    pub event: Pubkey,
+    /// The SHA-256 hash of the code.
+    pub code_hash: [u8; 32],
+    /// The discount, usage cap, validity window and section restriction of the code.
+    pub config: PromoCodeConfig,
+    /// The number of tickets bought with the code so far.
+    pub redemptions: u64,
+    /// The bump seed for the promo code PDA.
+    pub bump: u8,
+}
+
+/// A compact, per-event registry of lite tickets, used instead of NFTs for large events.
+///
+/// The registry is allocated by the admin up front (it can be far larger than what a
+/// program can allocate through CPI) and holds a fixed-size `LiteTicket` entry per ticket id
+/// right after this header, so a ticket costs no rent to the buyer.
+#[account(zero_copy)]
+pub struct LiteRegistry {
+    /// The public key of the `Event` this registry belongs to.
+    pub event: Pubkey,
+    /// The number of ticket entries the account has room for.
+    pub capacity: u64,
+}
+
+impl LiteRegistry {
+    /// The offset of the first ticket entry in the account data.
+    pub const ENTRIES_OFFSET: usize = DISCRIMINATOR_LENGHT + std::mem::size_of::<LiteRegistry>();
+
+    /// Returns the account size needed to hold `tickets` ticket entries.
+    pub fn space_for(tickets: u64) -> usize {
+        Self::ENTRIES_OFFSET + tickets as usize * LiteTicket::INIT_SPACE
+    }
+
+    /// Returns the number of ticket entries an account of `data_len` bytes can hold.
+    pub fn capacity_for(data_len: usize) -> u64 {
+        (data_len.saturating_sub(Self::ENTRIES_OFFSET) / LiteTicket::INIT_SPACE) as u64
+    }
+
+    /// Returns the byte range of the entry of `ticket_id` in a registry of `data_len` bytes.
+    fn entry_range(data_len: usize, ticket_id: u64) -> Result<std::ops::Range<usize>> {
+        let start = usize::try_from(ticket_id)
+            .ok()
+            .and_then(|id| id.checked_mul(LiteTicket::INIT_SPACE))
+            .and_then(|offset| offset.checked_add(Self::ENTRIES_OFFSET))
+            .filter(|start| start + LiteTicket::INIT_SPACE <= data_len)
+            .ok_or(EventError::LiteRegistryFull)?;
+        Ok(start..start + LiteTicket::INIT_SPACE)
+    }
+
+    /// Reads the entry of `ticket_id` from the registry account `data`.
+    pub fn read_entry(data: &[u8], ticket_id: u64) -> Result<LiteTicket> {
+        let range = Self::entry_range(data.len(), ticket_id)?;
+        Ok(LiteTicket::try_from_slice(&data[range])?)
+    }
+
+    /// Writes the entry of `ticket_id` to the registry account `data`.
+    pub fn write_entry(data: &mut [u8], ticket_id: u64, entry: &LiteTicket) -> Result<()> {
+        let range = Self::entry_range(data.len(), ticket_id)?;
+        entry.serialize(&mut &mut data[range])?;
+        Ok(())
+    }
+}
+
+/// A ticket stored in a `LiteRegistry`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct LiteTicket {
+    /// The wallet currently holding the ticket.
     pub owner: Pubkey,
+    /// The price paid for the ticket in lamports, refunded when it is returned.
+    pub price_paid: u64,
+    /// The status of the ticket, one of the `LiteTicket` status constants.
+    pub status: u8,
+}
+
+impl LiteTicket {
+    /// The entry has not been sold.
+    pub const UNSOLD: u8 = 0;
+    /// The ticket is valid and can be used, transferred or returned.
+    pub const VALID: u8 = 1;
+    /// The ticket has been checked in.
+    pub const USED: u8 = 2;
+    /// The ticket has been returned and refunded.
+    pub const RETURNED: u8 = 3;
+}
+
+/// A per-event bitmap of ticket statuses, indexed by ticket id.
+///
+/// Each ticket takes a nibble of flags right after this header, so scanners can sync
+/// the status of every ticket of an event by reading a single account. The registry
+/// grows as tickets are sold.
+#[account(zero_copy)]
+pub struct TicketRegistry {
+    /// The public key of the `Event` this registry belongs to.
+    pub event: Pubkey,
+    /// The bump seed for the ticket registry PDA.
+    pub bump: u8,
+}
+
+impl TicketRegistry {
+    /// The ticket has been checked in.
+    pub const USED: u8 = 1 << 0;
+    /// The ticket has been returned and refunded.
+    pub const RETURNED: u8 = 1 << 1;
+    /// The ticket has been closed after the event.
+    pub const CLOSED: u8 = 1 << 2;
+
+    /// The offset of the first flags byte in the account data.
+    pub const FLAGS_OFFSET: usize = DISCRIMINATOR_LENGHT + std::mem::size_of::<TicketRegistry>();
+
+    /// Returns the account size needed to track `tickets` tickets.
+    pub fn space_for(tickets: u64) -> usize {
+        Self::FLAGS_OFFSET + tickets.div_ceil(2) as usize
+    }
+
+    /// Returns the number of tickets an account of `data_len` bytes can track.
+    pub fn capacity_for(data_len: usize) -> u64 {
+        data_len.saturating_sub(Self::FLAGS_OFFSET) as u64 * 2
+    }
+
+    /// Returns the index of the byte holding the flags of `ticket_id` and their bit shift.
+    fn position(data_len: usize, ticket_id: u64) -> Result<(usize, u32)> {
+        require!(
+            ticket_id < Self::capacity_for(data_len),
+            EventError::TicketNotInRegistry
+        );
+        Ok((
+            Self::FLAGS_OFFSET + (ticket_id / 2) as usize,
+            (ticket_id % 2) as u32 * 4,
+        ))
+    }
+
+    /// Returns the flags of `ticket_id` from the registry account `data`.
+    pub fn flags(data: &[u8], ticket_id: u64) -> Result<u8> {
+        let (index, shift) = Self::position(data.len(), ticket_id)?;
+        Ok((data[index] >> shift) & 0x0f)
+    }
+
+    /// Sets `flags` for `ticket_id` in the registry account `data`, keeping the flags already set.
+    pub fn set_flags(data: &mut [u8], ticket_id: u64, flags: u8) -> Result<()> {
+        let (index, shift) = Self::position(data.len(), ticket_id)?;
+        data[index] |= (flags & 0x0f) << shift;
+        Ok(())
+    }
 }
@@ -0,0 +1,381 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    ed25519_program,
+    sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+};
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{BaseStateWithExtensions, StateWithExtensions},
+    state::Mint as Token2022Mint,
+};
+use anchor_spl::token_2022::{
+    burn_checked, close_account, mint_to, BurnChecked, CloseAccount, MintTo,
+};
+use anchor_spl::token_interface::{
+    spl_token_metadata_interface::state::{Field, TokenMetadata},
+    token_metadata_update_field, TokenMetadataUpdateField,
+};
+
+use crate::{
+    constants::GATEKEEPER_TOKEN_MAX_AGE_SLOTS,
+    errors::EventError,
+    state::{AttestationPayload, AttestationRequirement, Event, GatekeeperToken},
+};
+
+/// Writes `fields` into the `additional_metadata` of a ticket mint.
+///
+/// Token-2022 reallocates the mint when the metadata grows, so the mint is first
+/// topped up from `payer` to stay rent exempt at its new size. The `ticket` PDA is
+/// the metadata update authority and signs with `signer_seeds`.
+pub fn update_ticket_metadata<'info>(
+    token_program: &AccountInfo<'info>,
+    ticket_mint: &AccountInfo<'info>,
+    ticket: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    fields: &[(&str, String)],
+) -> Result<()> {
+    // Work out how large the mint will be once all fields are written
+    let required_len = {
+        let data = ticket_mint.try_borrow_data()?;
+        let mint = StateWithExtensions::<Token2022Mint>::unpack(&data)?;
+        let mut metadata = mint.get_variable_len_extension::<TokenMetadata>()?;
+        let current_size = metadata.tlv_size_of()?;
+        for (key, value) in fields {
+            metadata.update(Field::Key(key.to_string()), value.clone());
+        }
+        let new_size = metadata.tlv_size_of()?;
+        data.len() + new_size.saturating_sub(current_size)
+    };
+
+    let required_lamports = Rent::get()?.minimum_balance(required_len);
+    let missing_lamports = required_lamports.saturating_sub(ticket_mint.lamports());
+    if missing_lamports > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: ticket_mint.clone(),
+                },
+            ),
+            missing_lamports,
+        )?;
+    }
+
+    for (key, value) in fields {
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                token_program.clone(),
+                TokenMetadataUpdateField {
+                    program_id: token_program.clone(),
+                    metadata: ticket_mint.clone(),
+                    update_authority: ticket.clone(),
+                },
+                signer_seeds,
+            ),
+            Field::Key(key.to_string()),
+            value.clone(),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Resizes a program-owned `account` to `new_len` bytes, topping it up from `payer`
+/// so it stays rent exempt, or refunding the rent it no longer needs to `payer`.
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let missing_lamports = required_lamports.saturating_sub(account.lamports());
+    if missing_lamports > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            missing_lamports,
+        )?;
+    }
+
+    let excess_lamports = account.lamports().saturating_sub(required_lamports);
+    if excess_lamports > 0 {
+        **account.try_borrow_mut_lamports()? -= excess_lamports;
+        **payer.try_borrow_mut_lamports()? += excess_lamports;
+    }
+
+    account.resize(new_len)?;
+
+    Ok(())
+}
+
+/// Burns a ticket NFT from `token_account` and closes its mint, sending the mint's rent
+/// to `destination`.
+///
+/// The `ticket` PDA is the permanent delegate and close authority of the mint and signs
+/// with `signer_seeds`, so no signature of the holder or the admin is needed.
+pub fn burn_and_close_ticket_mint<'info>(
+    token_program: &AccountInfo<'info>,
+    ticket_mint: &AccountInfo<'info>,
+    token_account: &AccountInfo<'info>,
+    ticket: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    burn_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            BurnChecked {
+                mint: ticket_mint.clone(),
+                from: token_account.clone(),
+                authority: ticket.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+        0,
+    )?;
+
+    close_account(CpiContext::new_with_signer(
+        token_program.clone(),
+        CloseAccount {
+            account: ticket_mint.clone(),
+            destination: destination.clone(),
+            authority: ticket.clone(),
+        },
+        signer_seeds,
+    ))
+}
+
+/// Moves a ticket NFT from the token account `from` to the token account `to` by burning
+/// it and minting it again.
+///
+/// The program cannot transfer the NFT itself, as the transfer hook would call back into
+/// it, so the `ticket` PDA uses its permanent delegate and mint authority instead and
+/// signs with `signer_seeds`. The transfer hook does not run.
+pub fn move_ticket_nft<'info>(
+    token_program: &AccountInfo<'info>,
+    ticket_mint: &AccountInfo<'info>,
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    ticket: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    burn_checked(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            BurnChecked {
+                mint: ticket_mint.clone(),
+                from: from.clone(),
+                authority: ticket.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+        0,
+    )?;
+
+    mint_to(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            MintTo {
+                mint: ticket_mint.clone(),
+                to: to.clone(),
+                authority: ticket.clone(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )
+}
+
+/// Closes an account owned by this program that is not managed by Anchor,
+/// such as the `ExtraAccountMetaList` of a ticket mint, sending its rent to `destination`.
+pub fn close_program_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    **destination.try_borrow_mut_lamports()? += account.lamports();
+    **account.try_borrow_mut_lamports()? = 0;
+    account.assign(&anchor_lang::system_program::ID);
+    account.resize(0)?;
+
+    Ok(())
+}
+
+/// Returns the messages `signer` signed in the Ed25519 program instructions placed
+/// before the current instruction in the transaction.
+///
+/// The Ed25519 program has already verified the signatures when this runs, so only
+/// its inputs are read back from `instructions_sysvar`. Signatures whose key or message
+/// live in another instruction are skipped, as that data is not covered by this check.
+pub fn ed25519_signed_messages(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+) -> Result<Vec<Vec<u8>>> {
+    // Layout of the Ed25519 program instruction data
+    const OFFSETS_START: usize = 2;
+    const OFFSETS_SIZE: usize = 14;
+    const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+    let read_u16 = |data: &[u8], at: usize| -> Option<u16> {
+        Some(u16::from_le_bytes(data.get(at..at + 2)?.try_into().ok()?))
+    };
+
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    let mut messages = Vec::new();
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+        if instruction.program_id != ed25519_program::ID {
+            continue;
+        }
+
+        let data = &instruction.data;
+        let signatures = data.first().copied().unwrap_or(0) as usize;
+        for signature in 0..signatures {
+            let offsets = OFFSETS_START + signature * OFFSETS_SIZE;
+            let (
+                Some(public_key_offset),
+                Some(public_key_instruction),
+                Some(message_offset),
+                Some(message_size),
+                Some(message_instruction),
+            ) = (
+                read_u16(data, offsets + 4),
+                read_u16(data, offsets + 6),
+                read_u16(data, offsets + 8),
+                read_u16(data, offsets + 10),
+                read_u16(data, offsets + 12),
+            )
+            else {
+                break;
+            };
+            if public_key_instruction != CURRENT_INSTRUCTION
+                || message_instruction != CURRENT_INSTRUCTION
+            {
+                continue;
+            }
+
+            let public_key_offset = public_key_offset as usize;
+            let message_offset = message_offset as usize;
+            let (Some(public_key), Some(message)) = (
+                data.get(public_key_offset..public_key_offset + 32),
+                data.get(message_offset..message_offset + message_size as usize),
+            ) else {
+                continue;
+            };
+            if public_key == signer.as_ref() {
+                messages.push(message.to_vec());
+            }
+        }
+    }
+
+    Ok(messages)
+}
+
+/// Verifies that the `requirement`'s issuer signed an `AttestationPayload` for `wallet`
+/// in an Ed25519 program instruction of the transaction, making the required claim
+/// and unexpired at `now`.
+pub fn verify_attestation(
+    instructions_sysvar: &AccountInfo,
+    requirement: &AttestationRequirement,
+    wallet: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    let attestation = ed25519_signed_messages(instructions_sysvar, &requirement.issuer)?
+        .iter()
+        .rev()
+        .filter_map(|message| AttestationPayload::try_from_slice(message).ok())
+        .find(|payload| payload.wallet == *wallet)
+        .ok_or(EventError::AttestationRequired)?;
+
+    require!(
+        attestation.claim.satisfies(&requirement.claim),
+        EventError::AttestationClaimMismatch
+    );
+    require!(now <= attestation.expiry, EventError::AttestationExpired);
+
+    Ok(())
+}
+
+/// Verifies that `gatekeeper` signed a `GatekeeperToken` for `buyer` at `event` in an
+/// Ed25519 program instruction of the transaction, issued no more than
+/// `GATEKEEPER_TOKEN_MAX_AGE_SLOTS` before `current_slot`.
+///
+/// Returns the token, which the caller must consume with `GatekeeperPass::consume`.
+pub fn verify_gatekeeper_token(
+    instructions_sysvar: &AccountInfo,
+    gatekeeper: &Pubkey,
+    event: &Pubkey,
+    buyer: &Pubkey,
+    current_slot: u64,
+) -> Result<GatekeeperToken> {
+    let token = ed25519_signed_messages(instructions_sysvar, gatekeeper)?
+        .iter()
+        .filter_map(|message| GatekeeperToken::try_from_slice(message).ok())
+        .filter(|token| token.event == *event && token.buyer == *buyer)
+        .max_by_key(|token| token.slot)
+        .ok_or(EventError::GatekeeperApprovalRequired)?;
+
+    require!(
+        token.slot <= current_slot && current_slot - token.slot <= GATEKEEPER_TOKEN_MAX_AGE_SLOTS,
+        EventError::GatekeeperTokenExpired
+    );
+
+    Ok(token)
+}
+
+/// Verifies that a purchase by `buyer` for `recipient` meets the event's attestation
+/// requirement and is approved by its gatekeeper, for events that have them.
+///
+/// The attestation is checked against the recipient, who holds the ticket, and the
+/// gatekeeper approval against the buyer. `cosigner` is the gatekeeper account that
+/// signed the transaction, if any. Attestations and gatekeeper tokens are read from
+/// `instructions_sysvar`.
+///
+/// Returns the gatekeeper token approving the purchase, if the gatekeeper did not
+/// co-sign it. The caller must consume it with `GatekeeperPass::consume`.
+pub fn verify_purchase_approval(
+    event: &Account<Event>,
+    instructions_sysvar: Option<&AccountInfo>,
+    cosigner: Option<Pubkey>,
+    buyer: &Pubkey,
+    recipient: &Pubkey,
+    clock: &Clock,
+) -> Result<Option<GatekeeperToken>> {
+    // Only wallets attested by the event's issuer can hold tickets
+    if let Some(requirement) = &event.attestation {
+        let instructions_sysvar = instructions_sysvar.ok_or(EventError::AttestationRequired)?;
+        verify_attestation(
+            instructions_sysvar,
+            requirement,
+            recipient,
+            clock.unix_timestamp,
+        )?;
+    }
+
+    // Purchases must be approved by the event's gatekeeper, by co-signing or with a token
+    match event.gatekeeper {
+        Some(gatekeeper) if cosigner != Some(gatekeeper) => {
+            let instructions_sysvar =
+                instructions_sysvar.ok_or(EventError::GatekeeperApprovalRequired)?;
+            verify_gatekeeper_token(
+                instructions_sysvar,
+                &gatekeeper,
+                &event.key(),
+                buyer,
+                clock.slot,
+            )
+            .map(Some)
+        }
+        _ => Ok(None),
+    }
+}
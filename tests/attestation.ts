@@ -0,0 +1,127 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { assert } from "chai";
+import { EventTickets } from "../target/types/event_tickets";
+
+describe("Attestation-Gated Sales", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const issuer = anchor.web3.Keypair.generate();
+  const buyer = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(17);
+  let eventPda: anchor.web3.PublicKey;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  // Wallet, Borsh-encoded `MinimumAge(age)` claim and expiry, signed by `signer`
+  const attestAge = (signer: anchor.web3.Keypair, wallet: anchor.web3.PublicKey, age: number, expiry: number) => {
+    const message = Buffer.concat([
+      wallet.toBuffer(),
+      Buffer.from([1, age]),
+      new anchor.BN(expiry).toArrayLike(Buffer, "le", 8),
+    ]);
+    return anchor.web3.Ed25519Program.createInstructionWithPrivateKey({ privateKey: signer.secretKey, message });
+  };
+
+  const mintTicket = (preInstructions: anchor.web3.TransactionInstruction[], recipient = buyer.publicKey) => program.methods
+    .mintTicket(eventId, null, null, null)
+    .accounts({
+      event: eventPda,
+      buyer: buyer.publicKey,
+      recipient,
+      instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      tokenProgram: TOKEN_2022_PROGRAM_ID
+    })
+    .preInstructions(preInstructions)
+    .signers([buyer])
+    .rpc();
+
+  before(async () => {
+    await provider.connection.requestAirdrop(buyer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+
+    await program.methods
+      .createEvent(
+        "Late Night Club",
+        "LNC",
+        "An 18+ club night.",
+        "https://example.com/nft.json",
+        new anchor.BN(Math.floor(Date.now() / 1000)),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL),
+        new anchor.BN(10),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 18, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("Attestation: Failed to create event in before block:", err));
+
+    await program.methods
+      .setAttestationRequirement(eventId, { issuer: issuer.publicKey, claim: { minimumAge: { 0: 18 } } })
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+  });
+
+  it("Rejects buyers without an attestation", async () => {
+    try {
+      await mintTicket([]);
+      assert.fail("Should have failed because the buyer has no attestation.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "AttestationRequired");
+    }
+  });
+
+  it("Rejects attestations from untrusted issuers, of other claims or expired", async () => {
+    const now = Math.floor(Date.now() / 1000);
+
+    try {
+      await mintTicket([attestAge(anchor.web3.Keypair.generate(), buyer.publicKey, 18, now + 600)]);
+      assert.fail("Should have failed because the issuer is not trusted.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "AttestationRequired");
+    }
+
+    try {
+      await mintTicket([attestAge(issuer, buyer.publicKey, 16, now + 600)]);
+      assert.fail("Should have failed because the buyer is too young.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "AttestationClaimMismatch");
+    }
+
+    try {
+      await mintTicket([attestAge(issuer, buyer.publicKey, 18, now - 600)]);
+      assert.fail("Should have failed because the attestation has expired.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "AttestationExpired");
+    }
+  });
+
+  it("Checks the attestation against the recipient of the ticket", async () => {
+    const recipient = anchor.web3.Keypair.generate().publicKey;
+
+    try {
+      await mintTicket([attestAge(issuer, buyer.publicKey, 21, Math.floor(Date.now() / 1000) + 600)], recipient);
+      assert.fail("Should have failed because the recipient has no attestation.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "AttestationRequired");
+    }
+  });
+
+  it("Sells a ticket to an attested buyer", async () => {
+    await mintTicket([attestAge(issuer, buyer.publicKey, 21, Math.floor(Date.now() / 1000) + 600)]);
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.isTrue(eventAccount.ticketsSold.eqn(1), "Attested buyer should get a ticket");
+  });
+});
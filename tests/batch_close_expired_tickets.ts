@@ -0,0 +1,170 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { assert } from "chai";
+import { EventTickets } from "../target/types/event_tickets";
+
+// Helper function to sleep for a given number of milliseconds
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe("Batch Close Expired Tickets", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const buyer = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(13);
+  const ticketCount = 4;
+  let eventPda: anchor.web3.PublicKey;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getTicketPda = (eventPubkey: anchor.web3.PublicKey, ticketId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket"), eventPubkey.toBuffer(), ticketId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getTicketMintPda = (eventPubkey: anchor.web3.PublicKey, ticketId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket_mint"), eventPubkey.toBuffer(), ticketId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getEventArchivePda = (eventPubkey: anchor.web3.PublicKey) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event_archive"), eventPubkey.toBuffer()],
+      program.programId
+    )[0];
+  };
+
+  const getTicketOwnershipPda = (mint: anchor.web3.PublicKey) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket_ownership"), mint.toBuffer()],
+      program.programId
+    )[0];
+  };
+
+  const getExtraAccountMetaListPda = (mint: anchor.web3.PublicKey) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("extra-account-metas"), mint.toBuffer()],
+      program.programId
+    )[0];
+  };
+
+  const ticketAccounts = (ticketId: number) => {
+    const mint = getTicketMintPda(eventPda, new anchor.BN(ticketId));
+    return [
+      getTicketPda(eventPda, new anchor.BN(ticketId)),
+      mint,
+      getTicketOwnershipPda(mint),
+      getExtraAccountMetaListPda(mint),
+      getAssociatedTokenAddressSync(mint, buyer.publicKey, false, TOKEN_2022_PROGRAM_ID),
+      buyer.publicKey,
+    ].map(pubkey => ({ pubkey, isSigner: false, isWritable: true }));
+  };
+
+  before(async () => {
+    await provider.connection.requestAirdrop(buyer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+
+    await program.methods
+      .createEvent(
+        "Pop-up Show",
+        "PUS",
+        "A short event cleaned up in bulk.",
+        "https://example.com/nft.json",
+        new anchor.BN(Math.floor(Date.now() / 1000)),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 5),
+        new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL),
+        new anchor.BN(10),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("BatchClose: Failed to create event in before block:", err));
+
+    for (let i = 0; i < ticketCount; i++) {
+      await program.methods
+        .mintTicket(eventId, null, null, null)
+        .accounts({ event: eventPda, buyer: buyer.publicKey, recipient: buyer.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+        .signers([buyer])
+        .rpc();
+    }
+
+    // Return one ticket so the batch has to skip it
+    const returnedTicket = getTicketPda(eventPda, new anchor.BN(1));
+    await program.methods
+      .returnTicket(eventId, new anchor.BN(1))
+      .accounts({
+        event: eventPda,
+        ticket: returnedTicket,
+        ticketMint: getTicketMintPda(eventPda, new anchor.BN(1)),
+        signer: buyer.publicKey,
+        rentRecipient: buyer.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID
+      })
+      .signers([buyer])
+      .rpc();
+
+    // Wait for the event to end
+    await sleep(7000);
+  });
+
+  it("Closes every expired ticket and skips the returned one", async () => {
+    const remainingAccounts = [0, 1, 2].flatMap(ticketAccounts);
+
+    await program.methods
+      .batchCloseExpiredTickets(eventId)
+      .accounts({ event: eventPda, admin: admin.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .remainingAccounts(remainingAccounts)
+      .rpc();
+
+    for (const ticketId of [0, 2]) {
+      const [ticket, mint, ownership, extraAccountMetaList] = ticketAccounts(ticketId);
+      assert.isNull(await provider.connection.getAccountInfo(ticket.pubkey), "Ticket should be closed");
+      assert.isNull(await provider.connection.getAccountInfo(mint.pubkey), "Mint should be closed");
+      assert.isNull(await provider.connection.getAccountInfo(ownership.pubkey), "Ownership should be closed");
+      assert.isNull(await provider.connection.getAccountInfo(extraAccountMetaList.pubkey), "Extra account metas should be closed");
+    }
+  });
+
+  it("Skips tickets that were already closed", async () => {
+    await program.methods
+      .batchCloseExpiredTickets(eventId)
+      .accounts({ event: eventPda, admin: admin.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .remainingAccounts(ticketAccounts(0))
+      .rpc();
+  });
+
+  it("Keeps closing tickets against the archive once the event is settled", async () => {
+    await program.methods
+      .withdrawFunds(eventId)
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+
+    const archivePda = getEventArchivePda(eventPda);
+    const archive = await program.account.eventArchive.fetch(archivePda);
+    assert.isTrue(archive.ticketsSold.eqn(ticketCount), "Archive should record the tickets sold");
+    assert.isTrue(archive.ticketsReturned.eqn(1), "Archive should record the returned ticket");
+
+    await program.methods
+      .batchCloseExpiredTickets(eventId)
+      .accounts({ event: null, eventArchive: archivePda, admin: admin.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .remainingAccounts(ticketAccounts(3))
+      .rpc();
+
+    const [ticket] = ticketAccounts(3);
+    assert.isNull(await provider.connection.getAccountInfo(ticket.pubkey), "Ticket should be closed");
+  });
+});
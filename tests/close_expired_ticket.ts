@@ -1,6 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
+import { getAssociatedTokenAddressSync, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
 import { assert } from "chai";
 import { EventTickets } from './../target/types/event_tickets';
 
@@ -49,15 +49,17 @@ describe("Close Expired Ticket", () => {
         new anchor.BN(Math.floor(Date.now() / 1000)),
         new anchor.BN(eventEndTime),
         new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL),
-        new anchor.BN(10)
+        new anchor.BN(10),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
       )
       .accounts({ event: eventPda, admin: admin.publicKey })
       .rpc()
       .catch(err => console.log("CloseExpiredTicket: Failed to create event: ", err));
 
     await program.methods
-      .mintTicket(eventId)
-      .accounts({ event: eventPda, buyer: buyer.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .mintTicket(eventId, null, null, null)
+      .accounts({ event: eventPda, buyer: buyer.publicKey, recipient: buyer.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
       .signers([buyer])
       .rpc()
       .catch(err => console.log("CloseExpiredTicket: Failed to mint ticket: ", err));
@@ -71,13 +73,14 @@ describe("Close Expired Ticket", () => {
     console.log("----------------------------------");
 
 
-    const adminBalanceBefore = await provider.connection.getBalance(admin.publicKey);
+    const buyerBalanceBefore = await provider.connection.getBalance(buyer.publicKey);
 
     await program.methods
       .closeExpiredTicket(eventId, ticketId)
       .accounts({
         event: eventPda,
         ticket: ticketPda,
+        rentRecipient: buyer.publicKey,
         admin: admin.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
       })
@@ -90,9 +93,16 @@ describe("Close Expired Ticket", () => {
     const closedTicket = await provider.connection.getAccountInfo(ticketPda);
     assert.isNull(closedTicket, "Ticket account should have been closed.");
 
-    const adminBalanceAfter = await provider.connection.getBalance(admin.publicKey);
-    console.log('Admin balance before:', adminBalanceBefore, 'after:', adminBalanceAfter);
-    assert.isTrue(adminBalanceAfter > adminBalanceBefore, "Admin balance should have increased.");
+    const extraAccountMetaListPda = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("extra-account-metas"), ticketAccount.mint.toBuffer()],
+      program.programId
+    )[0];
+    const closedExtraAccountMetaList = await provider.connection.getAccountInfo(extraAccountMetaListPda);
+    assert.isNull(closedExtraAccountMetaList, "ExtraAccountMetaList account should have been closed.");
+
+    const buyerBalanceAfter = await provider.connection.getBalance(buyer.publicKey);
+    console.log('Buyer balance before:', buyerBalanceBefore, 'after:', buyerBalanceAfter);
+    assert.isTrue(buyerBalanceAfter > buyerBalanceBefore, "The rent should be returned to the buyer who paid it.");
   });
 
   it("Fails if the event has not ended yet", async () => {
@@ -110,15 +120,17 @@ describe("Close Expired Ticket", () => {
         new anchor.BN(Math.floor(Date.now() / 1000) - 1000),
         new anchor.BN(Math.floor(Date.now() / 1000) + 5000), // Ends in 5 seconds
         new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL),
-        new anchor.BN(10)
+        new anchor.BN(10),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
       )
       .accounts({ event: futureEventPda, admin: admin.publicKey })
       .rpc()
       .catch(err => console.log("CloseExpiredTicket: Failed to create future event: ", err));
 
     await program.methods
-      .mintTicket(futureEventId)
-      .accounts({ event: futureEventPda, buyer: buyer.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .mintTicket(futureEventId, null, null, null)
+      .accounts({ event: futureEventPda, buyer: buyer.publicKey, recipient: buyer.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
       .signers([buyer])
       .rpc()
       .catch(err => console.log("CloseExpiredTicket: Failed to mint future ticket: ", err));
@@ -129,6 +141,7 @@ describe("Close Expired Ticket", () => {
         .accounts({
           event: futureEventPda,
           ticket: futureTicketPda,
+          rentRecipient: buyer.publicKey,
           admin: admin.publicKey,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
         })
@@ -138,4 +151,33 @@ describe("Close Expired Ticket", () => {
       assert.equal(err.error.errorCode.code, "EventNotEnded");
     }
   });
+
+  it("Does not let anyone clean up a ticket before the grace period has passed", async () => {
+    const futureEventId = new anchor.BN(1);
+    const futureEventPda = getEventPda(admin.publicKey, futureEventId);
+    const futureTicketPda = getTicketPda(futureEventPda, new anchor.BN(0));
+    const ticket = await program.account.ticket.fetch(futureTicketPda);
+    const ticketTokenAccount = getAssociatedTokenAddressSync(ticket.mint, buyer.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const cranker = anchor.web3.Keypair.generate();
+    await provider.connection.requestAirdrop(cranker.publicKey, anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+
+    try {
+      await program.methods
+        .cleanupExpiredTicket(futureEventId, new anchor.BN(0))
+        .accounts({
+          event: futureEventPda,
+          ticket: futureTicketPda,
+          ticketMint: ticket.mint,
+          ticketTokenAccount,
+          rentRecipient: ticket.payer,
+          cranker: cranker.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .signers([cranker])
+        .rpc();
+      assert.fail("Should have failed because the grace period has not passed.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "CleanupGracePeriodActive");
+    }
+  });
 });
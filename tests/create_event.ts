@@ -41,7 +41,9 @@ describe("Event Creation", () => {
         startTime,
         endTime,
         ticketPrice,
-        totalTickets
+        totalTickets,
+        { venue: "Convention Center", geohash: "u09tvw0", category: { conference: {} }, minAge: 18, utcOffsetMinutes: 60 },
+        false
       )
       .accounts({
         event: eventPda,
@@ -61,6 +63,62 @@ describe("Event Creation", () => {
     assert.equal(eventAccount.admin.toBase58(), admin.publicKey.toBase58());
     assert.equal(eventAccount.name, name);
     assert.isTrue(eventAccount.totalTickets.eq(totalTickets));
+    assert.equal(eventAccount.details.venue, "Convention Center");
+    assert.isDefined(eventAccount.details.category.conference, "Event should be a conference");
+    assert.equal(eventAccount.details.minAge, 18);
+  });
+
+  it("Updates the structured details of an event", async () => {
+    const [eventPda, _] = getEventPda(admin.publicKey, new anchor.BN(2));
+
+    try {
+      await program.methods
+        .updateEventDetails(new anchor.BN(2), { venue: "Convention Center", geohash: "invalid!", category: { conference: {} }, minAge: 18, utcOffsetMinutes: 60 })
+        .accounts({ event: eventPda, admin: admin.publicKey })
+        .rpc();
+      assert.fail("The transaction should have failed due to the invalid geohash.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidGeohash");
+    }
+
+    await program.methods
+      .updateEventDetails(new anchor.BN(2), { venue: "Expo Hall", geohash: "u09tvw0", category: { conference: {} }, minAge: 21, utcOffsetMinutes: 60 })
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.equal(eventAccount.details.venue, "Expo Hall");
+    assert.equal(eventAccount.details.minAge, 21);
+  });
+
+  it("Sizes the event account to its strings and resizes it on update", async () => {
+    const [eventPda, _] = getEventPda(admin.publicKey, new anchor.BN(2));
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const sizeBefore = (await provider.connection.getAccountInfo(eventPda)).data.length;
+
+    const longDescription = eventAccount.description + " Now with two days of workshops and a hackathon.";
+    await program.methods
+      .updateEventInfo(new anchor.BN(2), eventAccount.name, eventAccount.symbol, longDescription, eventAccount.metadataUri)
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+
+    const grownSize = (await provider.connection.getAccountInfo(eventPda)).data.length;
+    assert.equal(grownSize, sizeBefore + longDescription.length - eventAccount.description.length, "Account should grow with the description");
+
+    const balanceBefore = await provider.connection.getBalance(admin.publicKey);
+    await program.methods
+      .updateEventInfo(new anchor.BN(2), eventAccount.name, eventAccount.symbol, "Short.", eventAccount.metadataUri)
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+
+    const shrunkSize = (await provider.connection.getAccountInfo(eventPda)).data.length;
+    assert.isTrue(shrunkSize < sizeBefore, "Account should shrink with the description");
+    const balanceAfter = await provider.connection.getBalance(admin.publicKey);
+    assert.isTrue(balanceAfter > balanceBefore, "Admin should be refunded the rent no longer needed");
+
+    const updatedEvent = await program.account.event.fetch(eventPda);
+    assert.equal(updatedEvent.description, "Short.");
+    assert.equal(updatedEvent.details.venue, "Expo Hall", "Other fields should survive the resize");
   });
 
   it("Fails to create an event with a name that is too long", async () => {
@@ -76,7 +134,9 @@ describe("Event Creation", () => {
           new anchor.BN(Math.floor(Date.now() / 1000) + 1000),
           new anchor.BN(Math.floor(Date.now() / 1000) + 2000),
           new anchor.BN(100000000),
-          new anchor.BN(100)
+          new anchor.BN(100),
+          { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+          false
         )
         .accounts({
           event: eventPda,
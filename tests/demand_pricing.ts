@@ -0,0 +1,93 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { assert } from "chai";
+import { EventTickets } from "../target/types/event_tickets";
+
+describe("Demand Pricing", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const buyer = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(7);
+  const ticketPrice = new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL);
+  let eventPda: anchor.web3.PublicKey;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getTicketPda = (eventPubkey: anchor.web3.PublicKey, ticketId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket"), eventPubkey.toBuffer(), ticketId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const mintTicket = async (maxPrice: anchor.BN | null) => {
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const ticketId = eventAccount.ticketsSold;
+
+    await program.methods
+      .mintTicket(eventId, null, maxPrice, null)
+      .accounts({ event: eventPda, buyer: buyer.publicKey, recipient: buyer.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .signers([buyer])
+      .rpc();
+
+    return program.account.ticket.fetch(getTicketPda(eventPda, ticketId));
+  };
+
+  before(async () => {
+    await provider.connection.requestAirdrop(buyer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+
+    await program.methods
+      .createEvent(
+        "Filling Up Fest",
+        "FUF",
+        "An event that gets more expensive as it sells.",
+        "https://example.com/nft.json",
+        new anchor.BN(Math.floor(Date.now() / 1000)),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        ticketPrice,
+        new anchor.BN(10),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("DemandPricing: Failed to create event in before block:", err));
+
+    // +5% for every 10% of tickets sold
+    await program.methods
+      .configureDemandPricing(eventId, { stepped: { stepBps: 1000, increaseBps: 500 } })
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+  });
+
+  it("Raises the price as tickets are sold", async () => {
+    const firstTicket = await mintTicket(null);
+    const secondTicket = await mintTicket(null);
+
+    assert.isTrue(firstTicket.pricePaid.eq(ticketPrice), "First ticket is sold at the base price");
+    assert.isTrue(
+      secondTicket.pricePaid.eq(ticketPrice.muln(105).divn(100)),
+      "Second ticket is 5% more expensive"
+    );
+  });
+
+  it("Fails when the price exceeds the buyer's maximum", async () => {
+    try {
+      await mintTicket(ticketPrice);
+      assert.fail("Should have failed because the price is above the maximum.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PriceAboveMaximum");
+    }
+  });
+});
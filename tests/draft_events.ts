@@ -0,0 +1,130 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { assert } from "chai";
+import { EventTickets } from "../target/types/event_tickets";
+
+describe("Draft Events", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const buyer = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(15);
+  const abandonedEventId = new anchor.BN(16);
+  let eventPda: anchor.web3.PublicKey;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const createDraft = (id: anchor.BN) => program.methods
+    .createEvent(
+      "Secret Show",
+      "SS",
+      "A show that is announced once everything is ready.",
+      "https://example.com/nft.json",
+      new anchor.BN(Math.floor(Date.now() / 1000)),
+      new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+      new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL),
+      new anchor.BN(10),
+      { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+      true
+    )
+    .accounts({ event: getEventPda(admin.publicKey, id), admin: admin.publicKey })
+    .rpc();
+
+  before(async () => {
+    await provider.connection.requestAirdrop(buyer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+    await createDraft(eventId)
+      .catch(err => console.log("DraftEvents: Failed to create event in before block:", err));
+  });
+
+  it("Blocks ticket sales until the draft is published", async () => {
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.isDefined(eventAccount.status.draft, "Event should be a draft");
+
+    try {
+      await program.methods
+        .mintTicket(eventId, null, null, null)
+        .accounts({ event: eventPda, buyer: buyer.publicKey, recipient: buyer.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+        .signers([buyer])
+        .rpc();
+      assert.fail("Should have failed because the event is a draft.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "EventNotPublished");
+    }
+  });
+
+  it("Validates the full setup of the draft before publishing it", async () => {
+    await program.methods
+      .createWaitlist(eventId, new anchor.BN(600))
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+
+    try {
+      await program.methods
+        .publishEvent(eventId)
+        .accounts({ event: eventPda, admin: admin.publicKey })
+        .rpc();
+      assert.fail("Should have failed because the waitlist was not provided.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "WaitlistRequired");
+    }
+  });
+
+  it("Publishes the draft and puts its tickets on sale", async () => {
+    const waitlistPda = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("waitlist"), eventPda.toBuffer()],
+      program.programId
+    )[0];
+
+    await program.methods
+      .publishEvent(eventId)
+      .accounts({ event: eventPda, admin: admin.publicKey, waitlist: waitlistPda })
+      .rpc();
+
+    await program.methods
+      .mintTicket(eventId, null, null, null)
+      .accounts({ event: eventPda, buyer: buyer.publicKey, recipient: buyer.publicKey, waitlist: waitlistPda, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .signers([buyer])
+      .rpc();
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.isTrue(eventAccount.ticketsSold.eqn(1), "Ticket should be sold once the event is published");
+
+    try {
+      await program.methods
+        .deleteDraftEvent(eventId)
+        .accounts({ event: eventPda, admin: admin.publicKey })
+        .rpc();
+      assert.fail("Should have failed because the event is no longer a draft.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "EventNotDraft");
+    }
+  });
+
+  it("Deletes a draft and reclaims its rent", async () => {
+    const abandonedEventPda = getEventPda(admin.publicKey, abandonedEventId);
+    await createDraft(abandonedEventId);
+
+    const balanceBefore = await provider.connection.getBalance(admin.publicKey);
+
+    await program.methods
+      .deleteDraftEvent(abandonedEventId)
+      .accounts({ event: abandonedEventPda, admin: admin.publicKey })
+      .rpc();
+
+    const closedEvent = await provider.connection.getAccountInfo(abandonedEventPda);
+    assert.isNull(closedEvent, "Draft event should be closed");
+
+    const balanceAfter = await provider.connection.getBalance(admin.publicKey);
+    assert.isTrue(balanceAfter > balanceBefore, "Admin should reclaim the rent");
+  });
+});
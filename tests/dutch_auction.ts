@@ -0,0 +1,180 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { assert } from "chai";
+import { createHash } from "crypto";
+import { EventTickets } from "../target/types/event_tickets";
+
+// Helper function to sleep for a given number of milliseconds
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe("Dutch Auction", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const earlyBuyer = anchor.web3.Keypair.generate();
+  const lateBuyer = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(6);
+  const startPrice = new anchor.BN(0.05 * anchor.web3.LAMPORTS_PER_SOL);
+  const floorPrice = new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL);
+  let eventPda: anchor.web3.PublicKey;
+  let earlyTicketId: anchor.BN;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getTicketPda = (eventPubkey: anchor.web3.PublicKey, ticketId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket"), eventPubkey.toBuffer(), ticketId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  before(async () => {
+    await provider.connection.requestAirdrop(earlyBuyer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+    await provider.connection.requestAirdrop(lateBuyer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+
+    await program.methods
+      .createEvent(
+        "Hyped Drop",
+        "HD",
+        "A high-demand event sold by Dutch auction.",
+        "https://example.com/nft.json",
+        new anchor.BN(Math.floor(Date.now() / 1000)),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        floorPrice,
+        new anchor.BN(10),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("DutchAuction: Failed to create event in before block:", err));
+  });
+
+  it("Configures a Dutch auction", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    await program.methods
+      .configureAuction(eventId, {
+        startPrice,
+        floorPrice,
+        startTime: new anchor.BN(now - 1),
+        endTime: new anchor.BN(now + 4),
+        curve: { linear: {} },
+        rebateToClearingPrice: true,
+      })
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.isDefined(eventAccount.pricing.dutchAuction, "Event should be sold by auction");
+  });
+
+  it("Charges a decreasing price and records it on each ticket", async () => {
+    let eventAccount = await program.account.event.fetch(eventPda);
+    earlyTicketId = eventAccount.ticketsSold;
+
+    await program.methods
+      .mintTicket(eventId, null, null, null)
+      .accounts({ event: eventPda, buyer: earlyBuyer.publicKey, recipient: earlyBuyer.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .signers([earlyBuyer])
+      .rpc();
+
+    // Wait for the auction to reach its floor price
+    await sleep(5000);
+
+    eventAccount = await program.account.event.fetch(eventPda);
+    const lateTicketId = eventAccount.ticketsSold;
+
+    await program.methods
+      .mintTicket(eventId, null, null, null)
+      .accounts({ event: eventPda, buyer: lateBuyer.publicKey, recipient: lateBuyer.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .signers([lateBuyer])
+      .rpc();
+
+    const earlyTicket = await program.account.ticket.fetch(getTicketPda(eventPda, earlyTicketId));
+    const lateTicket = await program.account.ticket.fetch(getTicketPda(eventPda, lateTicketId));
+    assert.isTrue(earlyTicket.pricePaid.gt(floorPrice), "Early buyers pay more than the floor price");
+    assert.isTrue(lateTicket.pricePaid.eq(floorPrice), "Late buyers pay the floor price");
+  });
+
+  it("Keeps the clearing price undiscounted when a promo code is redeemed", async () => {
+    const code = "FREEPASS";
+    const codeHash = createHash("sha256").update(code).digest();
+    const promoCodePda = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("promo_code"), eventPda.toBuffer(), codeHash],
+      program.programId
+    )[0];
+    const now = Math.floor(Date.now() / 1000);
+    await program.methods
+      .createPromoCode(eventId, Array.from(codeHash), {
+        discount: { percentage: { bps: 10000 } },
+        maxRedemptions: new anchor.BN(1),
+        validFrom: new anchor.BN(now - 60),
+        validUntil: new anchor.BN(now + 3600),
+        allowedSections: Buffer.from([]),
+      })
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+
+    const eventBefore = await program.account.event.fetch(eventPda);
+    await program.methods
+      .mintTicket(eventId, null, null, code)
+      .accounts({ event: eventPda, buyer: lateBuyer.publicKey, recipient: lateBuyer.publicKey, promoCode: promoCodePda, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .signers([lateBuyer])
+      .rpc();
+
+    const freeTicket = await program.account.ticket.fetch(getTicketPda(eventPda, eventBefore.ticketsSold));
+    assert.isTrue(freeTicket.pricePaid.eqn(0), "Promo code should make the ticket free");
+
+    const eventAfter = await program.account.event.fetch(eventPda);
+    assert.isTrue(eventAfter.clearingPrice.eq(floorPrice), "Clearing price should ignore the promo discount");
+  });
+
+  it("Refunds early buyers down to the clearing price", async () => {
+    const ticketPda = getTicketPda(eventPda, earlyTicketId);
+    const balanceBefore = await provider.connection.getBalance(earlyBuyer.publicKey);
+
+    await program.methods
+      .claimAuctionRebate(eventId, earlyTicketId)
+      .accounts({
+        event: eventPda,
+        ticket: ticketPda,
+        holder: earlyBuyer.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID
+      })
+      .signers([earlyBuyer])
+      .rpc();
+
+    const balanceAfter = await provider.connection.getBalance(earlyBuyer.publicKey);
+    assert.isTrue(balanceAfter > balanceBefore, "Buyer should receive the rebate");
+    assert.isAtMost(balanceAfter - balanceBefore, startPrice.sub(floorPrice).toNumber(), "Rebate should stop at the clearing price");
+
+    const ticket = await program.account.ticket.fetch(ticketPda);
+    assert.isTrue(ticket.pricePaid.eq(floorPrice), "Ticket should now record the clearing price");
+
+    try {
+      await program.methods
+        .claimAuctionRebate(eventId, earlyTicketId)
+        .accounts({
+          event: eventPda,
+          ticket: ticketPda,
+          holder: earlyBuyer.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID
+        })
+        .signers([earlyBuyer])
+        .rpc();
+      assert.fail("Should not be able to claim the rebate twice.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "NothingToRebate");
+    }
+  });
+});
@@ -0,0 +1,143 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { assert } from "chai";
+import { EventTickets } from "../target/types/event_tickets";
+
+describe("Event Lifecycle", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const buyer = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(14);
+  let eventPda: anchor.web3.PublicKey;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getTicketPda = (eventPubkey: anchor.web3.PublicKey, ticketId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket"), eventPubkey.toBuffer(), ticketId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const mintTicket = () => program.methods
+    .mintTicket(eventId, null, null, null)
+    .accounts({ event: eventPda, buyer: buyer.publicKey, recipient: buyer.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+    .signers([buyer])
+    .rpc();
+
+  const updateStatus = (status: any) => program.methods
+    .updateEventStatus(eventId, status)
+    .accounts({ event: eventPda, admin: admin.publicKey })
+    .rpc();
+
+  before(async () => {
+    await provider.connection.requestAirdrop(buyer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+
+    await program.methods
+      .createEvent(
+        "Rain Check Festival",
+        "RCF",
+        "An outdoor festival at the mercy of the weather.",
+        "https://example.com/nft.json",
+        new anchor.BN(Math.floor(Date.now() / 1000)),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL),
+        new anchor.BN(10),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("EventStatus: Failed to create event in before block:", err));
+  });
+
+  it("Lets anyone record that the event has gone live", async () => {
+    let eventAccount = await program.account.event.fetch(eventPda);
+    assert.isDefined(eventAccount.status.onSale, "New events should be on sale");
+
+    await program.methods
+      .advanceEventStatus(eventId)
+      .accounts({ event: eventPda, cranker: buyer.publicKey })
+      .signers([buyer])
+      .rpc();
+
+    eventAccount = await program.account.event.fetch(eventPda);
+    assert.isDefined(eventAccount.status.live, "Event should be live once it has started");
+
+    try {
+      await program.methods
+        .advanceEventStatus(eventId)
+        .accounts({ event: eventPda, cranker: buyer.publicKey })
+        .signers([buyer])
+        .rpc();
+      assert.fail("Should have failed because the status is already up to date.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "EventStatusUnchanged");
+    }
+  });
+
+  it("Pauses and resumes ticket sales", async () => {
+    await updateStatus({ salesClosed: {} });
+
+    try {
+      await mintTicket();
+      assert.fail("Should have failed because sales are closed.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "SalesClosed");
+    }
+
+    await updateStatus({ onSale: {} });
+    await mintTicket();
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.isTrue(eventAccount.ticketsSold.eqn(1), "Ticket should be sold once sales resume");
+  });
+
+  it("Cancels the event and lets holders return their tickets", async () => {
+    await updateStatus({ cancelled: {} });
+
+    try {
+      await mintTicket();
+      assert.fail("Should have failed because the event is cancelled.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "EventCancelled");
+    }
+
+    try {
+      await updateStatus({ onSale: {} });
+      assert.fail("Should have failed because a cancelled event cannot be reopened.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidStatusTransition");
+    }
+
+    const ticketPda = getTicketPda(eventPda, new anchor.BN(0));
+    const ticket = await program.account.ticket.fetch(ticketPda);
+
+    await program.methods
+      .returnTicket(eventId, new anchor.BN(0))
+      .accounts({
+        event: eventPda,
+        ticket: ticketPda,
+        ticketMint: ticket.mint,
+        signer: buyer.publicKey,
+        rentRecipient: buyer.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID
+      })
+      .signers([buyer])
+      .rpc();
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.isTrue(eventAccount.ticketsReturned.eqn(1), "Ticket should be returned after the cancellation");
+  });
+});
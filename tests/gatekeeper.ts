@@ -0,0 +1,147 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { assert } from "chai";
+import { EventTickets } from "../target/types/event_tickets";
+
+describe("Gatekeeper", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const gatekeeper = anchor.web3.Keypair.generate();
+  const buyer = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(18);
+  let eventPda: anchor.web3.PublicKey;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getGatekeeperPassPda = (eventPubkey: anchor.web3.PublicKey, buyerPubkey: anchor.web3.PublicKey) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("gatekeeper_pass"), eventPubkey.toBuffer(), buyerPubkey.toBuffer()],
+      program.programId
+    )[0];
+  };
+
+  // Event, buyer wallet and slot, signed by the gatekeeper
+  const gatekeeperToken = (event: anchor.web3.PublicKey, wallet: anchor.web3.PublicKey, slot: number) => {
+    const message = Buffer.concat([event.toBuffer(), wallet.toBuffer(), new anchor.BN(slot).toArrayLike(Buffer, "le", 8)]);
+    return anchor.web3.Ed25519Program.createInstructionWithPrivateKey({ privateKey: gatekeeper.secretKey, message });
+  };
+
+  const mintTicket = (accounts: object, preInstructions: anchor.web3.TransactionInstruction[], signers: anchor.web3.Keypair[]) => program.methods
+    .mintTicket(eventId, null, null, null)
+    .accounts({
+      event: eventPda,
+      buyer: buyer.publicKey,
+      recipient: buyer.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      ...accounts
+    })
+    .preInstructions(preInstructions)
+    .signers([buyer, ...signers])
+    .rpc();
+
+  before(async () => {
+    await provider.connection.requestAirdrop(buyer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+
+    await program.methods
+      .createEvent(
+        "Stadium Tour",
+        "ST",
+        "A tour that bots love to scalp.",
+        "https://example.com/nft.json",
+        new anchor.BN(Math.floor(Date.now() / 1000)),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL),
+        new anchor.BN(10),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("Gatekeeper: Failed to create event in before block:", err));
+
+    await program.methods
+      .setGatekeeper(eventId, gatekeeper.publicKey)
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+  });
+
+  it("Rejects purchases the gatekeeper did not approve", async () => {
+    try {
+      await mintTicket({}, [], []);
+      assert.fail("Should have failed because the gatekeeper did not approve the purchase.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "GatekeeperApprovalRequired");
+    }
+  });
+
+  it("Sells a ticket co-signed by the gatekeeper", async () => {
+    await mintTicket({ gatekeeper: gatekeeper.publicKey }, [], [gatekeeper]);
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.isTrue(eventAccount.ticketsSold.eqn(1), "Co-signed purchase should succeed");
+  });
+
+  it("Sells a ticket with a recent gatekeeper token and rejects tokens for other slots", async () => {
+    const slot = await provider.connection.getSlot();
+    const accounts = {
+      instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      gatekeeperPass: getGatekeeperPassPda(eventPda, buyer.publicKey)
+    };
+
+    try {
+      await mintTicket(accounts, [gatekeeperToken(eventPda, buyer.publicKey, slot + 1000)], []);
+      assert.fail("Should have failed because the token is not for a recent slot.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "GatekeeperTokenExpired");
+    }
+
+    try {
+      const otherEvent = getEventPda(admin.publicKey, new anchor.BN(1));
+      await mintTicket(accounts, [gatekeeperToken(otherEvent, buyer.publicKey, slot)], []);
+      assert.fail("Should have failed because the token was issued for another event.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "GatekeeperApprovalRequired");
+    }
+
+    await mintTicket(accounts, [gatekeeperToken(eventPda, buyer.publicKey, slot)], []);
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.isTrue(eventAccount.ticketsSold.eqn(2), "Purchase with a fresh token should succeed");
+
+    const pass = await program.account.gatekeeperPass.fetch(accounts.gatekeeperPass);
+    assert.equal(pass.lastSlot.toNumber(), slot, "Pass should record the token used");
+  });
+
+  it("Rejects a gatekeeper token that was already used", async () => {
+    const pass = await program.account.gatekeeperPass.fetch(getGatekeeperPassPda(eventPda, buyer.publicKey));
+    const accounts = {
+      instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      gatekeeperPass: getGatekeeperPassPda(eventPda, buyer.publicKey)
+    };
+
+    try {
+      await mintTicket(accounts, [gatekeeperToken(eventPda, buyer.publicKey, pass.lastSlot.toNumber())], []);
+      assert.fail("Should have failed because the token was already used.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "GatekeeperTokenUsed");
+    }
+
+    try {
+      await mintTicket({ instructionsSysvar: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY }, [gatekeeperToken(eventPda, buyer.publicKey, await provider.connection.getSlot())], []);
+      assert.fail("Should have failed because the gatekeeper pass is missing.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "GatekeeperPassRequired");
+    }
+  });
+});
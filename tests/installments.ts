@@ -0,0 +1,172 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { assert } from "chai";
+import { EventTickets } from "../target/types/event_tickets";
+
+// Helper function to sleep for a given number of milliseconds
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe("Installment Payments", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const buyer = anchor.web3.Keypair.generate();
+  const lateBuyer = anchor.web3.Keypair.generate();
+  const cranker = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(20);
+  const ticketPrice = new anchor.BN(0.1 * anchor.web3.LAMPORTS_PER_SOL);
+  let eventPda: anchor.web3.PublicKey;
+  let vaultPda: anchor.web3.PublicKey;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getReservationPda = (eventPubkey: anchor.web3.PublicKey, buyerPubkey: anchor.web3.PublicKey) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("reservation"), eventPubkey.toBuffer(), buyerPubkey.toBuffer()],
+      program.programId
+    )[0];
+  };
+
+  const getTicketPda = (eventPubkey: anchor.web3.PublicKey, ticketId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket"), eventPubkey.toBuffer(), ticketId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const configureInstallments = (interval: number) => program.methods
+    .configureInstallments(eventId, { depositBps: 2000, installments: 2, interval: new anchor.BN(interval) })
+    .accounts({ event: eventPda, admin: admin.publicKey })
+    .rpc();
+
+  const reserve = (wallet: anchor.web3.Keypair) => program.methods
+    .reserveTicket(eventId)
+    .accounts({ event: eventPda, buyer: wallet.publicKey })
+    .signers([wallet])
+    .rpc();
+
+  const claimTicket = () => program.methods
+    .mintTicket(eventId, null, null, null)
+    .accounts({
+      event: eventPda,
+      buyer: buyer.publicKey,
+      recipient: buyer.publicKey,
+      reservation: getReservationPda(eventPda, buyer.publicKey),
+      tokenProgram: TOKEN_2022_PROGRAM_ID
+    })
+    .signers([buyer])
+    .rpc();
+
+  before(async () => {
+    for (const wallet of [buyer, lateBuyer, cranker]) {
+      await provider.connection.requestAirdrop(wallet.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+    }
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+    vaultPda = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("vault"), eventPda.toBuffer()], program.programId)[0];
+
+    await program.methods
+      .createEvent(
+        "Summer Festival",
+        "SF",
+        "A three-day festival with pricey passes.",
+        "https://example.com/nft.json",
+        new anchor.BN(Math.floor(Date.now() / 1000) + 3600),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 4 * 86400),
+        ticketPrice,
+        new anchor.BN(10),
+        { venue: "Main Hall", geohash: "", category: { festival: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("Installments: Failed to create event in before block:", err));
+
+    await configureInstallments(600);
+  });
+
+  it("Reserves a ticket with a deposit and holds it", async () => {
+    await reserve(buyer);
+
+    const reservation = await program.account.reservation.fetch(getReservationPda(eventPda, buyer.publicKey));
+    assert.isTrue(reservation.price.eq(ticketPrice), "Reservation should lock the ticket price");
+    assert.isTrue(reservation.paid.eq(ticketPrice.divn(5)), "Deposit should be 20% of the price");
+    assert.equal(reservation.schedule.length, 2);
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.isTrue(eventAccount.ticketsReserved.eqn(1), "Reservation should hold a ticket");
+
+    try {
+      await claimTicket();
+      assert.fail("Should have failed because the reservation is not fully paid.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "ReservationNotPaid");
+    }
+  });
+
+  it("Issues the ticket once every installment is paid", async () => {
+    const payInstallment = () => program.methods
+      .payInstallment(eventId)
+      .accounts({ event: eventPda, buyer: buyer.publicKey })
+      .signers([buyer])
+      .rpc();
+
+    await payInstallment();
+    await payInstallment();
+
+    try {
+      await payInstallment();
+      assert.fail("Should have failed because the reservation is fully paid.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "ReservationAlreadyPaid");
+    }
+
+    await claimTicket();
+
+    const ticket = await program.account.ticket.fetch(getTicketPda(eventPda, new anchor.BN(0)));
+    assert.isTrue(ticket.pricePaid.eq(ticketPrice), "Ticket should record the reserved price");
+
+    const closedReservation = await provider.connection.getAccountInfo(getReservationPda(eventPda, buyer.publicKey));
+    assert.isNull(closedReservation, "Reservation should be closed once claimed");
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.isTrue(eventAccount.ticketsReserved.eqn(0), "Held ticket should be released");
+    assert.isTrue(eventAccount.ticketsSold.eqn(1));
+  });
+
+  it("Lets anyone cancel a reservation that missed an installment and forfeits the deposit", async () => {
+    await configureInstallments(2);
+    await reserve(lateBuyer);
+
+    const cancel = () => program.methods
+      .cancelReservation(eventId)
+      .accounts({ event: eventPda, buyer: lateBuyer.publicKey, cranker: cranker.publicKey })
+      .signers([cranker])
+      .rpc();
+
+    try {
+      await cancel();
+      assert.fail("Should have failed because no installment is overdue yet.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "ReservationNotCancellable");
+    }
+
+    await sleep(3000);
+    const vaultBefore = await provider.connection.getBalance(vaultPda);
+    await cancel();
+
+    const vaultAfter = await provider.connection.getBalance(vaultPda);
+    assert.equal(vaultAfter - vaultBefore, ticketPrice.divn(5).toNumber(), "Deposit should be forfeited to the vault");
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.isTrue(eventAccount.ticketsReserved.eqn(0), "Held ticket should be released");
+  });
+});
@@ -0,0 +1,192 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  createBurnCheckedInstruction,
+  createCloseAccountInstruction,
+  createTransferCheckedWithTransferHookInstruction,
+  getAssociatedTokenAddressSync,
+  getAccount,
+  TOKEN_2022_PROGRAM_ID
+} from "@solana/spl-token";
+import { assert } from "chai";
+import { EventTickets } from "../target/types/event_tickets";
+
+// Helper function to sleep for a given number of milliseconds
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe("Ticket Lending", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const owner = anchor.web3.Keypair.generate();
+  const borrower = anchor.web3.Keypair.generate();
+  const stranger = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(19);
+  const ticketId = new anchor.BN(0);
+  let eventPda: anchor.web3.PublicKey;
+  let ticketPda: anchor.web3.PublicKey;
+  let ticketMint: anchor.web3.PublicKey;
+  let ownershipPda: anchor.web3.PublicKey;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getTicketPda = (eventPubkey: anchor.web3.PublicKey, ticketId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket"), eventPubkey.toBuffer(), ticketId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getTicketOwnershipPda = (mint: anchor.web3.PublicKey) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket_ownership"), mint.toBuffer()],
+      program.programId
+    )[0];
+  };
+
+  const reclaim = (caller: anchor.web3.Keypair, borrowerTicketAta = getAssociatedTokenAddressSync(ticketMint, borrower.publicKey, false, TOKEN_2022_PROGRAM_ID)) => program.methods
+    .reclaimTicket(ticketId)
+    .accounts({
+      ticket: ticketPda,
+      ticketMint,
+      borrowerTicketAta,
+      owner: owner.publicKey,
+      caller: caller.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID
+    })
+    .signers([caller])
+    .rpc();
+
+  before(async () => {
+    for (const wallet of [owner, borrower, stranger]) {
+      await provider.connection.requestAirdrop(wallet.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+    }
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+    ticketPda = getTicketPda(eventPda, ticketId);
+
+    await program.methods
+      .createEvent(
+        "Season Opener",
+        "SO",
+        "The first home game of the season.",
+        "https://example.com/nft.json",
+        new anchor.BN(Math.floor(Date.now() / 1000)),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL),
+        new anchor.BN(10),
+        { venue: "Main Hall", geohash: "", category: { sports: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("Lending: Failed to create event in before block:", err));
+
+    await program.methods
+      .mintTicket(eventId, null, null, null)
+      .accounts({ event: eventPda, buyer: owner.publicKey, recipient: owner.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .signers([owner])
+      .rpc();
+
+    ticketMint = (await program.account.ticket.fetch(ticketPda)).mint;
+    ownershipPda = getTicketOwnershipPda(ticketMint);
+  });
+
+  const lend = (lentUntil: number) => program.methods
+    .lendTicket(eventId, ticketId, new anchor.BN(lentUntil))
+    .accounts({
+      event: eventPda,
+      ticket: ticketPda,
+      ticketMint,
+      lender: owner.publicKey,
+      borrower: borrower.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID
+    })
+    .signers([owner])
+    .rpc();
+
+  it("Lends a ticket to a borrower until a deadline", async () => {
+    await lend(Math.floor(Date.now() / 1000) + 3);
+
+    const ownership = await program.account.ticketOwnership.fetch(ownershipPda);
+    assert.equal(ownership.owner.toBase58(), owner.publicKey.toBase58(), "Lender should stay the owner");
+    assert.equal(ownership.user.toBase58(), borrower.publicKey.toBase58(), "Borrower should be the current user");
+
+    const borrowerAta = getAssociatedTokenAddressSync(ticketMint, borrower.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const tokenAccount = await getAccount(provider.connection, borrowerAta, "confirmed", TOKEN_2022_PROGRAM_ID);
+    assert.equal(tokenAccount.amount, BigInt(1), "Borrower should hold the ticket NFT");
+  });
+
+  it("Blocks transfers and early reclaims during the loan", async () => {
+    const borrowerAta = getAssociatedTokenAddressSync(ticketMint, borrower.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const ownerAta = getAssociatedTokenAddressSync(ticketMint, owner.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const transferInstruction = await createTransferCheckedWithTransferHookInstruction(
+      provider.connection,
+      borrowerAta,
+      ticketMint,
+      ownerAta,
+      borrower.publicKey,
+      BigInt(1),
+      0,
+      [],
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID
+    );
+    try {
+      await provider.sendAndConfirm(new anchor.web3.Transaction().add(transferInstruction), [borrower]);
+      assert.fail("Should have failed because the ticket is lent out.");
+    } catch (err) {
+      assert.notEqual(err.message, "Should have failed because the ticket is lent out.");
+    }
+
+    try {
+      await reclaim(stranger);
+      assert.fail("Should have failed because the loan has not ended.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "LoanActive");
+    }
+  });
+
+  it("Lets anyone reclaim the ticket after the deadline", async () => {
+    await sleep(4000);
+    await reclaim(stranger);
+
+    const ownership = await program.account.ticketOwnership.fetch(ownershipPda);
+    assert.equal(ownership.user.toBase58(), owner.publicKey.toBase58(), "Owner should be the current user again");
+    assert.isNull(ownership.lentUntil, "Loan should be cleared");
+
+    const ownerAta = getAssociatedTokenAddressSync(ticketMint, owner.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const tokenAccount = await getAccount(provider.connection, ownerAta, "confirmed", TOKEN_2022_PROGRAM_ID);
+    assert.equal(tokenAccount.amount, BigInt(1), "Owner should hold the ticket NFT again");
+  });
+
+  it("Mints the ticket to the owner again when the borrower burned it", async () => {
+    await lend(Math.floor(Date.now() / 1000) + 3);
+
+    const borrowerAta = getAssociatedTokenAddressSync(ticketMint, borrower.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    await provider.sendAndConfirm(
+      new anchor.web3.Transaction().add(
+        createBurnCheckedInstruction(borrowerAta, ticketMint, borrower.publicKey, BigInt(1), 0, [], TOKEN_2022_PROGRAM_ID),
+        createCloseAccountInstruction(borrowerAta, borrower.publicKey, borrower.publicKey, [], TOKEN_2022_PROGRAM_ID)
+      ),
+      [borrower]
+    );
+
+    await sleep(4000);
+    await reclaim(stranger, null);
+
+    const ownership = await program.account.ticketOwnership.fetch(ownershipPda);
+    assert.isNull(ownership.lentUntil, "Loan should be cleared");
+
+    const ownerAta = getAssociatedTokenAddressSync(ticketMint, owner.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const tokenAccount = await getAccount(provider.connection, ownerAta, "confirmed", TOKEN_2022_PROGRAM_ID);
+    assert.equal(tokenAccount.amount, BigInt(1), "Owner should hold the ticket NFT again");
+  });
+});
@@ -0,0 +1,179 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { assert } from "chai";
+import { EventTickets } from "../target/types/event_tickets";
+
+describe("Lite Tickets", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const buyer = anchor.web3.Keypair.generate();
+  const friend = anchor.web3.Keypair.generate();
+  const registry = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(11);
+  const totalTickets = 2;
+  const ticketPrice = new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL);
+  const ticketId = new anchor.BN(0);
+  // Discriminator + header (event, capacity) + one entry (owner, price paid, status) per ticket
+  const registrySize = 8 + 40 + 41 * totalTickets;
+  let eventPda: anchor.web3.PublicKey;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const readEntry = async (ticketId: number) => {
+    const info = await provider.connection.getAccountInfo(registry.publicKey);
+    const offset = 48 + 41 * ticketId;
+    return {
+      owner: new anchor.web3.PublicKey(info.data.subarray(offset, offset + 32)),
+      status: info.data[offset + 40],
+    };
+  };
+
+  before(async () => {
+    for (const wallet of [buyer, friend]) {
+      await provider.connection.requestAirdrop(wallet.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+    }
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+
+    await program.methods
+      .createEvent(
+        "Stadium Show",
+        "SS",
+        "A large event sold without NFTs.",
+        "https://example.com/nft.json",
+        new anchor.BN(Math.floor(Date.now() / 1000)),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        ticketPrice,
+        new anchor.BN(totalTickets),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("LiteTickets: Failed to create event in before block:", err));
+  });
+
+  it("Creates the lite ticket registry", async () => {
+    const rent = await provider.connection.getMinimumBalanceForRentExemption(registrySize);
+
+    await program.methods
+      .createLiteRegistry(eventId)
+      .accounts({ event: eventPda, liteRegistry: registry.publicKey, admin: admin.publicKey })
+      .preInstructions([
+        anchor.web3.SystemProgram.createAccount({
+          fromPubkey: admin.publicKey,
+          newAccountPubkey: registry.publicKey,
+          space: registrySize,
+          lamports: rent,
+          programId: program.programId,
+        }),
+      ])
+      .signers([registry])
+      .rpc();
+
+    const registryAccount = await program.account.liteRegistry.fetch(registry.publicKey);
+    assert.isTrue(registryAccount.capacity.eqn(totalTickets));
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.equal(eventAccount.liteRegistry.toBase58(), registry.publicKey.toBase58());
+  });
+
+  it("Buys a lite ticket without minting an NFT", async () => {
+    await program.methods
+      .mintLiteTicket(eventId, null)
+      .accounts({ event: eventPda, liteRegistry: registry.publicKey, buyer: buyer.publicKey, recipient: buyer.publicKey })
+      .signers([buyer])
+      .rpc();
+
+    const entry = await readEntry(0);
+    assert.equal(entry.owner.toBase58(), buyer.publicKey.toBase58());
+    assert.equal(entry.status, 1, "Ticket should be valid");
+  });
+
+  it("Transfers the ticket to a friend", async () => {
+    await program.methods
+      .transferLiteTicket(eventId, ticketId)
+      .accounts({ event: eventPda, liteRegistry: registry.publicKey, holder: buyer.publicKey, newOwner: friend.publicKey })
+      .signers([buyer])
+      .rpc();
+
+    const entry = await readEntry(0);
+    assert.equal(entry.owner.toBase58(), friend.publicKey.toBase58());
+  });
+
+  it("Checks in the ticket with the holder's signature", async () => {
+    await program.methods
+      .useLiteTicket(eventId, ticketId)
+      .accounts({ event: eventPda, liteRegistry: registry.publicKey, admin: admin.publicKey, holder: friend.publicKey })
+      .signers([friend])
+      .rpc();
+
+    const entry = await readEntry(0);
+    assert.equal(entry.status, 2, "Ticket should be used");
+
+    try {
+      await program.methods
+        .returnLiteTicket(eventId, ticketId)
+        .accounts({ event: eventPda, liteRegistry: registry.publicKey, holder: friend.publicKey })
+        .signers([friend])
+        .rpc();
+      assert.fail("Should have failed because the ticket is used.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "TicketAlreadyUsed");
+    }
+  });
+
+  it("Returns an unused ticket for a refund", async () => {
+    await program.methods
+      .mintLiteTicket(eventId, null)
+      .accounts({ event: eventPda, liteRegistry: registry.publicKey, buyer: buyer.publicKey, recipient: buyer.publicKey })
+      .signers([buyer])
+      .rpc();
+
+    await program.methods
+      .returnLiteTicket(eventId, new anchor.BN(1))
+      .accounts({ event: eventPda, liteRegistry: registry.publicKey, holder: buyer.publicKey })
+      .signers([buyer])
+      .rpc();
+
+    const entry = await readEntry(1);
+    assert.equal(entry.status, 3, "Ticket should be returned");
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.isTrue(eventAccount.ticketsReturned.eqn(1));
+  });
+
+  it("Resells a returned ticket past the registry's initial capacity", async () => {
+    await program.methods
+      .mintLiteTicket(eventId, null)
+      .accounts({ event: eventPda, liteRegistry: registry.publicKey, buyer: friend.publicKey, recipient: friend.publicKey })
+      .signers([friend])
+      .rpc();
+
+    const entry = await readEntry(totalTickets);
+    assert.equal(entry.owner.toBase58(), friend.publicKey.toBase58());
+    assert.equal(entry.status, 1, "Resold ticket should be valid");
+
+    const registryAccount = await program.account.liteRegistry.fetch(registry.publicKey);
+    assert.isTrue(registryAccount.capacity.eqn(totalTickets + 1), "Registry should grow by one entry");
+
+    try {
+      await program.methods
+        .mintLiteTicket(eventId, null)
+        .accounts({ event: eventPda, liteRegistry: registry.publicKey, buyer: buyer.publicKey, recipient: buyer.publicKey })
+        .signers([buyer])
+        .rpc();
+      assert.fail("Should have failed because every ticket is sold.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "EventSoldOut");
+    }
+  });
+});
@@ -0,0 +1,271 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { assert } from "chai";
+import { createHash, randomBytes } from "crypto";
+import { EventTickets } from "../target/types/event_tickets";
+
+// Helper function to sleep for a given number of milliseconds
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe("Ticket Lottery", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const fans = [anchor.web3.Keypair.generate(), anchor.web3.Keypair.generate()];
+  const stranger = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(8);
+  const deposit = new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL);
+  const secret = randomBytes(32);
+  let eventPda: anchor.web3.PublicKey;
+  let lotteryPda: anchor.web3.PublicKey;
+  let drawSlot: number;
+  let winner: anchor.web3.Keypair;
+  let loser: anchor.web3.Keypair;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getLotteryPda = (eventPubkey: anchor.web3.PublicKey) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("lottery"), eventPubkey.toBuffer()],
+      program.programId
+    )[0];
+  };
+
+  const getLotteryEntryPda = (lotteryPubkey: anchor.web3.PublicKey, entrant: anchor.web3.PublicKey) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("lottery_entry"), lotteryPubkey.toBuffer(), entrant.toBuffer()],
+      program.programId
+    )[0];
+  };
+
+  before(async () => {
+    for (const fan of [...fans, stranger]) {
+      await provider.connection.requestAirdrop(fan.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+    }
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+    lotteryPda = getLotteryPda(eventPda);
+
+    const now = Math.floor(Date.now() / 1000);
+    await program.methods
+      .createEvent(
+        "Stadium Tour",
+        "ST",
+        "An oversubscribed event allocated by lottery.",
+        "https://example.com/nft.json",
+        new anchor.BN(now),
+        new anchor.BN(now + 86400),
+        new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL),
+        new anchor.BN(2),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("Lottery: Failed to create event in before block:", err));
+
+    // Seed the draw with a slot shortly after the entry window closes
+    drawSlot = (await provider.connection.getSlot()) + 25;
+    await program.methods
+      .createLottery(eventId, {
+        entryStart: new anchor.BN(now - 1),
+        entryEnd: new anchor.BN(now + 4),
+        claimEnd: new anchor.BN(now + 3600),
+        winners: new anchor.BN(1),
+        deposit,
+        drawSlot: new anchor.BN(drawSlot),
+        commitment: Array.from(createHash("sha256").update(secret).digest()),
+      })
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+  });
+
+  it("Registers fans and holds their deposits", async () => {
+    for (const fan of fans) {
+      await program.methods
+        .enterLottery(eventId)
+        .accounts({ event: eventPda, entrant: fan.publicKey })
+        .signers([fan])
+        .rpc();
+    }
+
+    const lottery = await program.account.lottery.fetch(lotteryPda);
+    assert.isTrue(lottery.entries.eqn(2), "Both fans should be registered");
+  });
+
+  it("Draws the winners with the revealed secret once the draw slot has passed", async () => {
+    const draw = () => program.methods
+      .drawLottery(eventId, Array.from(secret))
+      .accounts({ event: eventPda, cranker: admin.publicKey })
+      .rpc();
+
+    // Wait for the entry window to close
+    await sleep(5000);
+    if ((await provider.connection.getSlot()) <= drawSlot) {
+      try {
+        await draw();
+        assert.fail("Should have failed because the draw slot has not passed.");
+      } catch (err) {
+        assert.equal(err.error.errorCode.code, "LotteryDrawSlotNotReached");
+      }
+    }
+    while ((await provider.connection.getSlot()) <= drawSlot) {
+      await sleep(400);
+    }
+
+    await draw();
+
+    const lottery = await program.account.lottery.fetch(lotteryPda);
+    assert.isTrue(lottery.drawn, "Lottery should be drawn");
+  });
+
+  it("Rejects purchases with an entry its entrant did not sign for", async () => {
+    for (const fan of fans) {
+      try {
+        await program.methods
+          .mintTicket(eventId, null, null, null)
+          .accounts({
+            event: eventPda,
+            buyer: stranger.publicKey,
+            recipient: fan.publicKey,
+            lottery: lotteryPda,
+            lotteryEntry: getLotteryEntryPda(lotteryPda, fan.publicKey),
+            tokenProgram: TOKEN_2022_PROGRAM_ID
+          })
+          .signers([stranger])
+          .rpc();
+        assert.fail("Should have failed because the entrant did not sign.");
+      } catch (err) {
+        assert.equal(err.error.errorCode.code, "LotteryEntrantNotSigner");
+      }
+    }
+  });
+
+  it("Lets only the winner buy a ticket and returns the deposit", async () => {
+    const buy = (fan: anchor.web3.Keypair) => program.methods
+      .mintTicket(eventId, null, null, null)
+      .accounts({
+        event: eventPda,
+        buyer: fan.publicKey,
+        recipient: fan.publicKey,
+        lottery: lotteryPda,
+        lotteryEntry: getLotteryEntryPda(lotteryPda, fan.publicKey),
+        tokenProgram: TOKEN_2022_PROGRAM_ID
+      })
+      .signers([fan])
+      .rpc();
+
+    // Exactly one of the two fans won the single ticket
+    for (const fan of fans) {
+      try {
+        await buy(fan);
+        winner = fan;
+      } catch (err) {
+        assert.equal(err.error.errorCode.code, "NotALotteryWinner");
+        loser = fan;
+      }
+    }
+    assert.isDefined(winner, "One fan should have won");
+    assert.isDefined(loser, "One fan should have lost");
+
+    const closedEntry = await provider.connection.getAccountInfo(getLotteryEntryPda(lotteryPda, winner.publicKey));
+    assert.isNull(closedEntry, "Winning entry should be closed after the purchase.");
+  });
+
+  it("Refunds the deposit of a losing entry", async () => {
+    const entryPda = getLotteryEntryPda(lotteryPda, loser.publicKey);
+    const balanceBefore = await provider.connection.getBalance(loser.publicKey);
+
+    await program.methods
+      .claimLotteryRefund(eventId)
+      .accounts({ event: eventPda, lottery: lotteryPda, entrant: loser.publicKey })
+      .signers([loser])
+      .rpc();
+
+    const balanceAfter = await provider.connection.getBalance(loser.publicKey);
+    assert.isTrue(balanceAfter - balanceBefore >= deposit.toNumber() - 10000, "Deposit should be refunded");
+
+    const closedEntry = await provider.connection.getAccountInfo(entryPda);
+    assert.isNull(closedEntry, "Losing entry should be closed after the refund.");
+  });
+
+  it("Lets anyone draw without the secret once the admin withholds it past the reveal window", async () => {
+    const withheldEventId = new anchor.BN(23);
+    const withheldEventPda = getEventPda(admin.publicKey, withheldEventId);
+    const withheldLotteryPda = getLotteryPda(withheldEventPda);
+    const now = Math.floor(Date.now() / 1000);
+
+    await program.methods
+      .createEvent(
+        "Reunion Show",
+        "RS",
+        "A lottery whose organizer never reveals the secret.",
+        "https://example.com/nft.json",
+        new anchor.BN(now),
+        new anchor.BN(now + 86400),
+        new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL),
+        new anchor.BN(2),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: withheldEventPda, admin: admin.publicKey })
+      .rpc();
+
+    const withheldDrawSlot = (await provider.connection.getSlot()) + 10;
+    await program.methods
+      .createLottery(withheldEventId, {
+        entryStart: new anchor.BN(now - 1),
+        entryEnd: new anchor.BN(now + 2),
+        claimEnd: new anchor.BN(now + 3600),
+        winners: new anchor.BN(1),
+        deposit,
+        drawSlot: new anchor.BN(withheldDrawSlot),
+        commitment: Array.from(createHash("sha256").update(randomBytes(32)).digest()),
+      })
+      .accounts({ event: withheldEventPda, admin: admin.publicKey })
+      .rpc();
+
+    await program.methods
+      .enterLottery(withheldEventId)
+      .accounts({ event: withheldEventPda, entrant: stranger.publicKey })
+      .signers([stranger])
+      .rpc();
+
+    const drawWithoutSecret = () => program.methods
+      .drawLottery(withheldEventId, null)
+      .accounts({ event: withheldEventPda, cranker: stranger.publicKey })
+      .signers([stranger])
+      .rpc();
+
+    // Wait for the draw slot and the entry window to pass
+    while ((await provider.connection.getSlot()) <= withheldDrawSlot) {
+      await sleep(400);
+    }
+    await sleep(3000);
+
+    try {
+      await drawWithoutSecret();
+      assert.fail("Should have failed because the reveal window is still open.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidLotterySecret");
+    }
+
+    // Wait for the reveal window (`LOTTERY_REVEAL_WINDOW_SLOTS`) to close
+    while ((await provider.connection.getSlot()) <= withheldDrawSlot + 256) {
+      await sleep(1000);
+    }
+
+    await drawWithoutSecret();
+
+    const lottery = await program.account.lottery.fetch(withheldLotteryPda);
+    assert.isTrue(lottery.drawn, "Anyone should be able to draw once the secret is withheld");
+  });
+});
@@ -0,0 +1,146 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { assert } from "chai";
+import { EventTickets } from "../target/types/event_tickets";
+
+describe("Loyalty", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const fan = anchor.web3.Keypair.generate();
+  const newcomer = anchor.web3.Keypair.generate();
+
+  const pastEventId = new anchor.BN(21);
+  const nextEventId = new anchor.BN(22);
+  const ticketPrice = new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL);
+  let pastEventPda: anchor.web3.PublicKey;
+  let nextEventPda: anchor.web3.PublicKey;
+  let loyaltyPda: anchor.web3.PublicKey;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getTicketPda = (eventPubkey: anchor.web3.PublicKey, ticketId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket"), eventPubkey.toBuffer(), ticketId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getLoyaltyPda = (organizer: anchor.web3.PublicKey, wallet: anchor.web3.PublicKey) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("loyalty"), organizer.toBuffer(), wallet.toBuffer()],
+      program.programId
+    )[0];
+  };
+
+  const createEvent = (eventPda: anchor.web3.PublicKey, name: string, startTime: number) => program.methods
+    .createEvent(
+      name,
+      "LY",
+      "An event for returning fans.",
+      "https://example.com/nft.json",
+      new anchor.BN(startTime),
+      new anchor.BN(startTime + 86400),
+      ticketPrice,
+      new anchor.BN(10),
+      { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+      false
+    )
+    .accounts({ event: eventPda, admin: admin.publicKey })
+    .rpc();
+
+  const mintTicket = (eventId: anchor.BN, eventPda: anchor.web3.PublicKey, wallet: anchor.web3.Keypair, accounts: object) => program.methods
+    .mintTicket(eventId, null, null, null)
+    .accounts({
+      event: eventPda,
+      buyer: wallet.publicKey,
+      recipient: wallet.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      ...accounts
+    })
+    .signers([wallet])
+    .rpc();
+
+  before(async () => {
+    for (const wallet of [fan, newcomer]) {
+      await provider.connection.requestAirdrop(wallet.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+    }
+
+    pastEventPda = getEventPda(admin.publicKey, pastEventId);
+    nextEventPda = getEventPda(admin.publicKey, nextEventId);
+    loyaltyPda = getLoyaltyPda(admin.publicKey, fan.publicKey);
+
+    const now = Math.floor(Date.now() / 1000);
+    await createEvent(pastEventPda, "Spring Tour", now)
+      .catch(err => console.log("Loyalty: Failed to create past event in before block:", err));
+    await createEvent(nextEventPda, "Autumn Tour", now + 3600)
+      .catch(err => console.log("Loyalty: Failed to create next event in before block:", err));
+
+    await mintTicket(pastEventId, pastEventPda, fan, {});
+  });
+
+  it("Records attendance when a ticket is used", async () => {
+    const ticketPda = getTicketPda(pastEventPda, new anchor.BN(0));
+    const ticket = await program.account.ticket.fetch(ticketPda);
+
+    await program.methods
+      .useTicket(pastEventId, new anchor.BN(0))
+      .accounts({
+        event: pastEventPda,
+        ticket: ticketPda,
+        ticketMint: ticket.mint,
+        admin: admin.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID
+      })
+      .rpc();
+
+    const record = await program.account.loyaltyRecord.fetch(loyaltyPda);
+    assert.equal(record.organizer.toBase58(), admin.publicKey.toBase58());
+    assert.equal(record.wallet.toBase58(), fan.publicKey.toBase58());
+    assert.equal(record.eventsAttended, 1, "Check-in should count towards attendance");
+    assert.equal(record.lastEvent.toBase58(), pastEventPda.toBase58());
+  });
+
+  it("Rejects loyalty programs without a perk", async () => {
+    try {
+      await program.methods
+        .setLoyaltyProgram(nextEventId, { minEvents: 1, discount: null, presaleEnd: null })
+        .accounts({ event: nextEventPda, admin: admin.publicKey })
+        .rpc();
+      assert.fail("Should have failed because the program grants no perk.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidLoyaltyProgram");
+    }
+  });
+
+  it("Reserves the presale for returning fans and gives them a discount", async () => {
+    const nextEvent = await program.account.event.fetch(nextEventPda);
+    await program.methods
+      .setLoyaltyProgram(nextEventId, {
+        minEvents: 1,
+        discount: { percentage: { bps: 2000 } },
+        presaleEnd: nextEvent.startTime
+      })
+      .accounts({ event: nextEventPda, admin: admin.publicKey })
+      .rpc();
+
+    try {
+      await mintTicket(nextEventId, nextEventPda, newcomer, {});
+      assert.fail("Should have failed because the newcomer has no attendance.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "LoyaltyPresaleOnly");
+    }
+
+    await mintTicket(nextEventId, nextEventPda, fan, { loyalty: loyaltyPda });
+
+    const ticket = await program.account.ticket.fetch(getTicketPda(nextEventPda, new anchor.BN(0)));
+    assert.isTrue(ticket.pricePaid.eq(ticketPrice.muln(8).divn(10)), "Returning fan should get 20% off");
+  });
+});
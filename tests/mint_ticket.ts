@@ -89,7 +89,9 @@ describe("Ticket Minting and Transfer Hook", () => {
           new anchor.BN(Math.floor(Date.now() / 1000)),
           new anchor.BN(Math.floor(Date.now() / 1000) + 7200),
           new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL),
-          new anchor.BN(10)
+          new anchor.BN(10),
+          { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+          false
         )
         .accounts({
           event: eventPda,
@@ -112,13 +114,14 @@ describe("Ticket Minting and Transfer Hook", () => {
 
 
     await program.methods
-      .mintTicket(eventId)
+      .mintTicket(eventId, null, null, null)
       .accounts({
         event: eventPda,
         eventVault: eventVaultPda,
         ticket: ticketPda,
         ticketMint: ticketMintPda,
         buyer: buyer.publicKey,
+        recipient: buyer.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID,
       })
       .signers([buyer])
@@ -194,4 +197,55 @@ describe("Ticket Minting and Transfer Hook", () => {
     assert.equal(ticketOwnershipAccount3.owner.toBase58(), ticketOwnershipAccount.owner.toBase58(), "Ticket owner should be the original buyer after second transfer");
 
   });
+
+  it("Mints a ticket as a gift for another wallet", async () => {
+    const eventAccountBefore = await program.account.event.fetch(eventPda);
+    const ticketNumber = eventAccountBefore.ticketsSold;
+    const giftMintPda = getTicketMintPda(eventPda, ticketNumber);
+
+    await program.methods
+      .mintTicket(eventId, null, null, null)
+      .accounts({
+        event: eventPda,
+        buyer: buyer.publicKey,
+        recipient: recipient.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([buyer])
+      .rpc();
+
+    const ownership = await program.account.ticketOwnership.fetch(getTiketOwnershipPda(giftMintPda));
+    assert.equal(ownership.owner.toBase58(), recipient.publicKey.toBase58(), "The recipient should own the gifted ticket");
+
+    const recipientAta = await getAssociatedTokenAddress(giftMintPda, recipient.publicKey, false, TOKEN_2022_PROGRAM_ID);
+    const balance = await provider.connection.getTokenAccountBalance(recipientAta);
+    assert.equal(balance.value.amount, "1", "The recipient should hold the ticket NFT");
+  });
+
+  it("Lets the rent payer hand the rent refund over to the holder", async () => {
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const giftTicketId = eventAccount.ticketsSold.subn(1);
+    const giftTicketPda = getTicketPda(eventPda, giftTicketId);
+
+    try {
+      await program.methods
+        .setRentRefundToHolder(eventId, giftTicketId, true)
+        .accounts({ event: eventPda, ticket: giftTicketPda, payer: recipient.publicKey })
+        .signers([recipient])
+        .rpc();
+      assert.fail("Should have failed because the recipient did not pay the rent.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "NotRentPayer");
+    }
+
+    await program.methods
+      .setRentRefundToHolder(eventId, giftTicketId, true)
+      .accounts({ event: eventPda, ticket: giftTicketPda, payer: buyer.publicKey })
+      .signers([buyer])
+      .rpc();
+
+    const ticket = await program.account.ticket.fetch(giftTicketPda);
+    assert.equal(ticket.payer.toBase58(), buyer.publicKey.toBase58());
+    assert.isTrue(ticket.refundRentToHolder, "Rent should be refunded to the holder");
+  });
 });
@@ -0,0 +1,167 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { assert } from "chai";
+import { createHash } from "crypto";
+import { EventTickets } from "../target/types/event_tickets";
+
+describe("Promo Codes", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const buyer = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(10);
+  const ticketPrice = new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL);
+  const code = "EARLY20";
+  const codeHash = createHash("sha256").update(code).digest();
+  let eventPda: anchor.web3.PublicKey;
+  let promoCodePda: anchor.web3.PublicKey;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getPromoCodePda = (eventPubkey: anchor.web3.PublicKey, codeHash: Buffer) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("promo_code"), eventPubkey.toBuffer(), codeHash],
+      program.programId
+    )[0];
+  };
+
+  const getTicketPda = (eventPubkey: anchor.web3.PublicKey, ticketId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket"), eventPubkey.toBuffer(), ticketId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  before(async () => {
+    await provider.connection.requestAirdrop(buyer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+    promoCodePda = getPromoCodePda(eventPda, codeHash);
+
+    await program.methods
+      .createEvent(
+        "Launch Party",
+        "LP",
+        "An event promoted with discount codes.",
+        "https://example.com/nft.json",
+        new anchor.BN(Math.floor(Date.now() / 1000)),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        ticketPrice,
+        new anchor.BN(10),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("PromoCodes: Failed to create event in before block:", err));
+  });
+
+  it("Creates a 20% promo code with a single redemption", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    await program.methods
+      .createPromoCode(eventId, Array.from(codeHash), {
+        discount: { percentage: { bps: 2000 } },
+        maxRedemptions: new anchor.BN(1),
+        validFrom: new anchor.BN(now - 60),
+        validUntil: new anchor.BN(now + 3600),
+        allowedSections: Buffer.from([]),
+      })
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+
+    const promoCode = await program.account.promoCode.fetch(promoCodePda);
+    assert.isTrue(promoCode.redemptions.eqn(0));
+  });
+
+  it("Fails to redeem a wrong code", async () => {
+    try {
+      await program.methods
+        .mintTicket(eventId, null, null, "EARLY30")
+        .accounts({ event: eventPda, buyer: buyer.publicKey, recipient: buyer.publicKey, promoCode: promoCodePda, tokenProgram: TOKEN_2022_PROGRAM_ID })
+        .signers([buyer])
+        .rpc();
+      assert.fail("Should have failed because the code does not match.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidPromoCode");
+    }
+  });
+
+  it("Charges the discounted price and counts the redemption", async () => {
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const ticketPda = getTicketPda(eventPda, eventAccount.ticketsSold);
+
+    await program.methods
+      .mintTicket(eventId, null, null, code)
+      .accounts({ event: eventPda, buyer: buyer.publicKey, recipient: buyer.publicKey, promoCode: promoCodePda, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .signers([buyer])
+      .rpc();
+
+    const ticket = await program.account.ticket.fetch(ticketPda);
+    assert.isTrue(ticket.pricePaid.eq(ticketPrice.muln(80).divn(100)), "Ticket should be 20% off");
+
+    const promoCode = await program.account.promoCode.fetch(promoCodePda);
+    assert.isTrue(promoCode.redemptions.eqn(1));
+  });
+
+  it("Fails once the code has reached its maximum redemptions", async () => {
+    try {
+      await program.methods
+        .mintTicket(eventId, null, null, code)
+        .accounts({ event: eventPda, buyer: buyer.publicKey, recipient: buyer.publicKey, promoCode: promoCodePda, tokenProgram: TOKEN_2022_PROGRAM_ID })
+        .signers([buyer])
+        .rpc();
+      assert.fail("Should have failed because the code is used up.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "PromoCodeExhausted");
+    }
+  });
+
+  it("Closes a code, reclaiming its rent and making it unusable", async () => {
+    const retiredCode = "VIP10";
+    const retiredCodeHash = createHash("sha256").update(retiredCode).digest();
+    const retiredCodePda = getPromoCodePda(eventPda, retiredCodeHash);
+    const now = Math.floor(Date.now() / 1000);
+
+    await program.methods
+      .createPromoCode(eventId, Array.from(retiredCodeHash), {
+        discount: { percentage: { bps: 1000 } },
+        maxRedemptions: new anchor.BN(100),
+        validFrom: new anchor.BN(now - 60),
+        validUntil: new anchor.BN(now + 3600),
+        allowedSections: Buffer.from([]),
+      })
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+
+    const rent = await provider.connection.getBalance(retiredCodePda);
+    const balanceBefore = await provider.connection.getBalance(admin.publicKey);
+
+    await program.methods
+      .closePromoCode(eventId)
+      .accounts({ event: eventPda, promoCode: retiredCodePda, admin: admin.publicKey })
+      .rpc();
+
+    const balanceAfter = await provider.connection.getBalance(admin.publicKey);
+    assert.isTrue(balanceAfter - balanceBefore >= rent - 10000, "Admin should reclaim the rent");
+    assert.isNull(await provider.connection.getAccountInfo(retiredCodePda), "Promo code should be closed");
+
+    try {
+      await program.methods
+        .mintTicket(eventId, null, null, retiredCode)
+        .accounts({ event: eventPda, buyer: buyer.publicKey, recipient: buyer.publicKey, promoCode: retiredCodePda, tokenProgram: TOKEN_2022_PROGRAM_ID })
+        .signers([buyer])
+        .rpc();
+      assert.fail("Should have failed because the code is closed.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "AccountNotInitialized");
+    }
+  });
+});
@@ -33,6 +33,13 @@ describe("Ticket Returns", () => {
     )[0];
   };
 
+  const getTicketOwnershipPda = (mint: anchor.web3.PublicKey) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket_ownership"), mint.toBuffer()],
+      program.programId
+    )[0];
+  };
+
   before(async () => {
     await provider.connection.requestAirdrop(buyer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
     await provider.connection.requestAirdrop(unauthorizedUser.publicKey, 1 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
@@ -53,7 +60,9 @@ describe("Ticket Returns", () => {
           new anchor.BN(Math.floor(Date.now() / 1000)),
           new anchor.BN(Math.floor(Date.now() / 1000) + 86400), // Ends in 24 hours
           new anchor.BN(1 * anchor.web3.LAMPORTS_PER_SOL), // 1 SOL price
-          new anchor.BN(5)
+          new anchor.BN(5),
+          { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+          false
         )
         .accounts({
           event: eventPda,
@@ -70,11 +79,12 @@ describe("Ticket Returns", () => {
     ticketId = eventAccount.ticketsSold; // Use the current count as the new ID
 
     await program.methods
-      .mintTicket(eventId)
+      .mintTicket(eventId, null, null, null)
       .accounts({
         event: eventPda,
         eventVault: eventVaultPda,
         buyer: buyer.publicKey,
+        recipient: buyer.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID
       })
       .signers([buyer])
@@ -99,6 +109,7 @@ describe("Ticket Returns", () => {
         ticket: ticketPda,
         ticketMint: ticketMintPda,
         signer: buyer.publicKey,
+        rentRecipient: buyer.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID
       })
       .signers([buyer])
@@ -124,5 +135,11 @@ describe("Ticket Returns", () => {
     // Verify the event's tickets_returned count was incremented
     const eventAccountAfter = await program.account.event.fetch(eventPda);
     assert.isTrue(eventAccountAfter.ticketsReturned > ticketsReturnedCount, "Tickets sold should decrement");
+
+    // Verify the mint and the transfer hook accounts were closed along with the ticket
+    const mintAccount = await provider.connection.getAccountInfo(ticketMintPda);
+    assert.isNull(mintAccount, "Ticket mint should have been closed.");
+    const ownershipAccount = await provider.connection.getAccountInfo(getTicketOwnershipPda(ticketMintPda));
+    assert.isNull(ownershipAccount, "Ticket ownership account should have been closed.");
   });
 });
\ No newline at end of file
@@ -0,0 +1,178 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { getTokenMetadata, TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { assert } from "chai";
+import { EventTickets } from "../target/types/event_tickets";
+
+describe("Reserved Seating", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const buyer = anchor.web3.Keypair.generate();
+  const secondBuyer = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(5);
+  const ticketPrice = new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL);
+  const balconyPrice = new anchor.BN(0.02 * anchor.web3.LAMPORTS_PER_SOL);
+  const balconySeat = { section: 1, row: 0, seat: 1 };
+  let eventPda: anchor.web3.PublicKey;
+  let seatMapPda: anchor.web3.PublicKey;
+  let ticketPda: anchor.web3.PublicKey;
+  let ticketId: anchor.BN;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getSeatMapPda = (eventPubkey: anchor.web3.PublicKey) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("seat_map"), eventPubkey.toBuffer()],
+      program.programId
+    )[0];
+  };
+
+  const getTicketPda = (eventPubkey: anchor.web3.PublicKey, ticketId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket"), eventPubkey.toBuffer(), ticketId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  before(async () => {
+    await provider.connection.requestAirdrop(buyer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+    await provider.connection.requestAirdrop(secondBuyer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+    seatMapPda = getSeatMapPda(eventPda);
+
+    await program.methods
+      .createEvent(
+        "Theater Night",
+        "TN",
+        "A theater show with reserved seating.",
+        "https://example.com/nft.json",
+        new anchor.BN(Math.floor(Date.now() / 1000)),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        ticketPrice,
+        new anchor.BN(4),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("SeatMap: Failed to create event in before block:", err));
+  });
+
+  it("Creates a seat map for the event", async () => {
+    await program.methods
+      .createSeatMap(eventId, [
+        { name: "Orchestra", rows: 1, seatsPerRow: 2, price: null },
+        { name: "Balcony", rows: 1, seatsPerRow: 2, price: balconyPrice },
+      ])
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+
+    const seatMap = await program.account.seatMap.fetch(seatMapPda);
+    assert.equal(seatMap.totalSeats, 4);
+    assert.equal(seatMap.sections.length, 2);
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    assert.isTrue(eventAccount.hasSeatMap, "Event should use reserved seating");
+  });
+
+  it("Sets a per-seat metadata URI template", async () => {
+    try {
+      await program.methods
+        .setUriTemplate(eventId, "https://example.com/tickets/{row}.json")
+        .accounts({ event: eventPda, admin: admin.publicKey })
+        .rpc();
+      assert.fail("Should have failed because of the unknown placeholder.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "InvalidUriTemplate");
+    }
+
+    await program.methods
+      .setUriTemplate(eventId, "https://example.com/tickets/{tier}/{seat}.json")
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+  });
+
+  it("Fails to mint a ticket without choosing a seat", async () => {
+    try {
+      await program.methods
+        .mintTicket(eventId, null, null, null)
+        .accounts({ event: eventPda, buyer: buyer.publicKey, recipient: buyer.publicKey, seatMap: seatMapPda, tokenProgram: TOKEN_2022_PROGRAM_ID })
+        .signers([buyer])
+        .rpc();
+      assert.fail("Should have failed because no seat was selected.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "SeatRequired");
+    }
+  });
+
+  it("Mints a ticket for a specific seat at the section price", async () => {
+    const eventAccount = await program.account.event.fetch(eventPda);
+    ticketId = eventAccount.ticketsSold;
+    ticketPda = getTicketPda(eventPda, ticketId);
+
+    await program.methods
+      .mintTicket(eventId, balconySeat, null, null)
+      .accounts({ event: eventPda, buyer: buyer.publicKey, recipient: buyer.publicKey, seatMap: seatMapPda, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .signers([buyer])
+      .rpc();
+
+    const ticket = await program.account.ticket.fetch(ticketPda);
+    assert.equal(ticket.seatLabel, "Balcony R1 S2");
+    assert.isTrue(ticket.pricePaid.eq(balconyPrice), "Ticket should record the section price");
+
+    const metadata = await getTokenMetadata(provider.connection, ticket.mint, "confirmed", TOKEN_2022_PROGRAM_ID);
+    const fields = new Map(metadata.additionalMetadata);
+    assert.equal(fields.get("seat"), "Balcony R1 S2", "Metadata should contain the seat label");
+    assert.equal(metadata.uri, "https://example.com/tickets/1/3.json", "Metadata URI should be expanded for the seat");
+  });
+
+  it("Fails to sell the same seat twice", async () => {
+    try {
+      await program.methods
+        .mintTicket(eventId, balconySeat, null, null)
+        .accounts({ event: eventPda, buyer: secondBuyer.publicKey, recipient: secondBuyer.publicKey, seatMap: seatMapPda, tokenProgram: TOKEN_2022_PROGRAM_ID })
+        .signers([secondBuyer])
+        .rpc();
+      assert.fail("Should have failed because the seat is already sold.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "SeatAlreadySold");
+    }
+  });
+
+  it("Frees the seat when the ticket is returned", async () => {
+    const ticket = await program.account.ticket.fetch(ticketPda);
+
+    await program.methods
+      .returnTicket(eventId, ticketId)
+      .accounts({
+        event: eventPda,
+        ticket: ticketPda,
+        ticketMint: ticket.mint,
+        signer: buyer.publicKey,
+        rentRecipient: buyer.publicKey,
+        seatMap: seatMapPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID
+      })
+      .signers([buyer])
+      .rpc();
+
+    await program.methods
+      .mintTicket(eventId, balconySeat, null, null)
+      .accounts({ event: eventPda, buyer: secondBuyer.publicKey, recipient: secondBuyer.publicKey, seatMap: seatMapPda, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .signers([secondBuyer])
+      .rpc();
+
+    const eventAccount = await program.account.event.fetch(eventPda);
+    const resoldTicket = await program.account.ticket.fetch(getTicketPda(eventPda, eventAccount.ticketsSold.subn(1)));
+    assert.equal(resoldTicket.seatLabel, "Balcony R1 S2", "The returned seat should be sold again");
+  });
+});
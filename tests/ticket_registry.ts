@@ -0,0 +1,156 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { assert } from "chai";
+import { EventTickets } from "../target/types/event_tickets";
+
+describe("Ticket Registry", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const buyer = anchor.web3.Keypair.generate();
+
+  const eventId = new anchor.BN(12);
+  const USED = 1;
+  const RETURNED = 2;
+  let eventPda: anchor.web3.PublicKey;
+  let registryPda: anchor.web3.PublicKey;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getTicketRegistryPda = (eventPubkey: anchor.web3.PublicKey) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket_registry"), eventPubkey.toBuffer()],
+      program.programId
+    )[0];
+  };
+
+  const getTicketPda = (eventPubkey: anchor.web3.PublicKey, ticketId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket"), eventPubkey.toBuffer(), ticketId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  // Discriminator + header (event, bump), followed by one nibble of flags per ticket
+  const readFlags = async (ticketId: number) => {
+    const info = await provider.connection.getAccountInfo(registryPda);
+    const byte = info.data[8 + 33 + Math.floor(ticketId / 2)];
+    return (byte >> ((ticketId % 2) * 4)) & 0x0f;
+  };
+
+  before(async () => {
+    await provider.connection.requestAirdrop(buyer.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+    registryPda = getTicketRegistryPda(eventPda);
+
+    await program.methods
+      .createEvent(
+        "Festival Gates",
+        "FG",
+        "An event checked in with a ticket registry.",
+        "https://example.com/nft.json",
+        new anchor.BN(Math.floor(Date.now() / 1000)),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL),
+        new anchor.BN(10),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("TicketRegistry: Failed to create event in before block:", err));
+
+    await program.methods
+      .createTicketRegistry(eventId)
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+
+    for (let i = 0; i < 3; i++) {
+      await program.methods
+        .mintTicket(eventId, null, null, null)
+        .accounts({
+          event: eventPda,
+          buyer: buyer.publicKey,
+          recipient: buyer.publicKey,
+          ticketRegistry: registryPda,
+          tokenProgram: TOKEN_2022_PROGRAM_ID
+        })
+        .signers([buyer])
+        .rpc();
+    }
+  });
+
+  it("Checks in several tickets in one instruction", async () => {
+    await program.methods
+      .batchUseTickets(eventId, [new anchor.BN(0), new anchor.BN(1)])
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+
+    assert.equal(await readFlags(0), USED);
+    assert.equal(await readFlags(1), USED);
+    assert.equal(await readFlags(2), 0, "Ticket 2 should still be valid");
+  });
+
+  it("Rejects returning a ticket checked in through a batch", async () => {
+    const ticketPda = getTicketPda(eventPda, new anchor.BN(1));
+    const ticket = await program.account.ticket.fetch(ticketPda);
+
+    try {
+      await program.methods
+        .returnTicket(eventId, new anchor.BN(1))
+        .accounts({
+          event: eventPda,
+          ticket: ticketPda,
+          ticketMint: ticket.mint,
+          signer: buyer.publicKey,
+          rentRecipient: buyer.publicKey,
+          ticketRegistry: registryPda,
+          tokenProgram: TOKEN_2022_PROGRAM_ID
+        })
+        .signers([buyer])
+        .rpc();
+      assert.fail("Should have failed because the ticket is used.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "TicketAlreadyUsed");
+    }
+  });
+
+  it("Records returns in the registry", async () => {
+    const ticketPda = getTicketPda(eventPda, new anchor.BN(2));
+    const ticket = await program.account.ticket.fetch(ticketPda);
+
+    await program.methods
+      .returnTicket(eventId, new anchor.BN(2))
+      .accounts({
+        event: eventPda,
+        ticket: ticketPda,
+        ticketMint: ticket.mint,
+        signer: buyer.publicKey,
+        rentRecipient: buyer.publicKey,
+        ticketRegistry: registryPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID
+      })
+      .signers([buyer])
+      .rpc();
+
+    assert.equal(await readFlags(2), RETURNED);
+
+    try {
+      await program.methods
+        .batchUseTickets(eventId, [new anchor.BN(2)])
+        .accounts({ event: eventPda, admin: admin.publicKey })
+        .rpc();
+      assert.fail("Should have failed because the ticket was returned.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "TicketNotActive");
+    }
+  });
+});
@@ -1,6 +1,6 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
-import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { getTokenMetadata, TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
 import { assert } from "chai";
 import { EventTickets } from "../target/types/event_tickets";
 
@@ -16,6 +16,7 @@ describe("Ticket Usage", () => {
   let eventPda: anchor.web3.PublicKey;
   let eventVaultPda: anchor.web3.PublicKey; // Added for minting
   let ticketPda: anchor.web3.PublicKey;
+  let ticketMintPda: anchor.web3.PublicKey;
   let ticketId: anchor.BN;
 
   const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
@@ -52,7 +53,9 @@ describe("Ticket Usage", () => {
           new anchor.BN(Math.floor(Date.now() / 1000)),
           new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
           new anchor.BN(1 * anchor.web3.LAMPORTS_PER_SOL),
-          new anchor.BN(10)
+          new anchor.BN(10),
+          { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+          false
         )
         .accounts({ event: eventPda, admin: admin.publicKey })
         .rpc()
@@ -66,11 +69,12 @@ describe("Ticket Usage", () => {
     ticketId = eventAccount.ticketsSold;
 
     await program.methods
-      .mintTicket(eventId)
+      .mintTicket(eventId, null, null, null)
       .accounts({
         event: eventPda,
         eventVault: eventVaultPda, // Pass the vault account
         buyer: buyer.publicKey,
+        recipient: buyer.publicKey,
         tokenProgram: TOKEN_2022_PROGRAM_ID
       })
       .signers([buyer])
@@ -78,6 +82,8 @@ describe("Ticket Usage", () => {
       .catch(err => console.log("UseTicket: Failed to mint ticket in before block:", err));
 
     ticketPda = getTicketPda(eventPda, ticketId);
+    const ticketAccount = await program.account.ticket.fetch(ticketPda);
+    ticketMintPda = ticketAccount.mint;
   });
 
   it("Successfully marks a ticket as used", async () => {
@@ -89,7 +95,9 @@ describe("Ticket Usage", () => {
       .accounts({
         event: eventPda,
         ticket: ticketPda,
-        admin: admin.publicKey
+        ticketMint: ticketMintPda,
+        admin: admin.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID
       })
       .rpc()
       .catch(err => console.log("UseTicket: Failed to use ticket:", err));
@@ -100,6 +108,14 @@ describe("Ticket Usage", () => {
     const ticketAfter = await program.account.ticket.fetch(ticketPda);
     console.log('Ticket after use:', ticketAfter);
     assert.isTrue(ticketAfter.used, "Ticket should be marked as used");
+
+    const metadata = await getTokenMetadata(provider.connection, ticketMintPda, "confirmed", TOKEN_2022_PROGRAM_ID);
+    const fields = new Map(metadata.additionalMetadata);
+    assert.equal(fields.get("status"), "used", "Metadata status should be updated on check-in");
+    assert.isDefined(fields.get("checked_in_at"), "Metadata should record the check-in time");
+    assert.equal(fields.get("event_id"), eventId.toString(), "Metadata should reference the event");
+    assert.equal(fields.get("venue"), "Main Hall", "Metadata should contain the venue");
+    assert.equal(fields.get("category"), "music", "Metadata should contain the category");
   });
 
   it("Fails to use a ticket that is already used", async () => {
@@ -109,7 +125,9 @@ describe("Ticket Usage", () => {
         .accounts({
           event: eventPda,
           ticket: ticketPda,
-          admin: admin.publicKey
+          ticketMint: ticketMintPda,
+          admin: admin.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID
         })
         .rpc();
       assert.fail("Should have failed to use an already used ticket.");
@@ -125,7 +143,9 @@ describe("Ticket Usage", () => {
         .accounts({
           event: eventPda,
           ticket: ticketPda,
+          ticketMint: ticketMintPda,
           admin: buyer.publicKey, // Using buyer as the admin signer
+          tokenProgram: TOKEN_2022_PROGRAM_ID
         })
         .signers([buyer]) // Buyer signs instead of admin
         .rpc();
@@ -0,0 +1,351 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID } from '@solana/spl-token';
+import { assert } from "chai";
+import { EventTickets } from "../target/types/event_tickets";
+
+// Helper function to sleep for a given number of milliseconds
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+describe("Waitlist", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+  const program = anchor.workspace.EventTickets as Program<EventTickets>;
+  const provider = anchor.getProvider() as anchor.AnchorProvider;
+  const admin = provider.wallet;
+  const holder = anchor.web3.Keypair.generate();
+  const waitingFan = anchor.web3.Keypair.generate();
+  const bot = anchor.web3.Keypair.generate();
+  const giftedFan = anchor.web3.Keypair.generate();
+  const [slowFan, nextFan, queuedFan] = [anchor.web3.Keypair.generate(), anchor.web3.Keypair.generate(), anchor.web3.Keypair.generate()];
+
+  const eventId = new anchor.BN(9);
+  const ticketPrice = new anchor.BN(0.01 * anchor.web3.LAMPORTS_PER_SOL);
+  const position = new anchor.BN(0);
+  let eventPda: anchor.web3.PublicKey;
+  let waitlistPda: anchor.web3.PublicKey;
+  let entryPda: anchor.web3.PublicKey;
+
+  const getEventPda = (adminPubkey: anchor.web3.PublicKey, eventId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event"), adminPubkey.toBuffer(), eventId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getWaitlistPda = (eventPubkey: anchor.web3.PublicKey) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("waitlist"), eventPubkey.toBuffer()],
+      program.programId
+    )[0];
+  };
+
+  const getWaitlistEntryPda = (waitlistPubkey: anchor.web3.PublicKey, position: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("waitlist_entry"), waitlistPubkey.toBuffer(), position.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const getTicketPda = (eventPubkey: anchor.web3.PublicKey, ticketId: anchor.BN) => {
+    return anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ticket"), eventPubkey.toBuffer(), ticketId.toArrayLike(Buffer, "be", 8)],
+      program.programId
+    )[0];
+  };
+
+  const returnTicket = async (ticketId: anchor.BN, wallet: anchor.web3.Keypair) => {
+    const ticketPda = getTicketPda(eventPda, ticketId);
+    const ticket = await program.account.ticket.fetch(ticketPda);
+
+    await program.methods
+      .returnTicket(eventId, ticketId)
+      .accounts({
+        event: eventPda,
+        ticket: ticketPda,
+        ticketMint: ticket.mint,
+        signer: wallet.publicKey,
+        rentRecipient: wallet.publicKey,
+        waitlist: waitlistPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID
+      })
+      .signers([wallet])
+      .rpc();
+  };
+
+  before(async () => {
+    for (const wallet of [holder, waitingFan, bot, giftedFan, slowFan, nextFan, queuedFan]) {
+      await provider.connection.requestAirdrop(wallet.publicKey, 2 * anchor.web3.LAMPORTS_PER_SOL).then(sig => provider.connection.confirmTransaction(sig));
+    }
+
+    eventPda = getEventPda(admin.publicKey, eventId);
+    waitlistPda = getWaitlistPda(eventPda);
+    entryPda = getWaitlistEntryPda(waitlistPda, position);
+
+    await program.methods
+      .createEvent(
+        "Intimate Gig",
+        "IG",
+        "A tiny sold-out show with a waitlist.",
+        "https://example.com/nft.json",
+        new anchor.BN(Math.floor(Date.now() / 1000)),
+        new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+        ticketPrice,
+        new anchor.BN(1),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
+      )
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc()
+      .catch(err => console.log("Waitlist: Failed to create event in before block:", err));
+
+    await program.methods
+      .createWaitlist(eventId, new anchor.BN(600))
+      .accounts({ event: eventPda, admin: admin.publicKey })
+      .rpc();
+
+    // Sell out the event
+    await program.methods
+      .mintTicket(eventId, null, null, null)
+      .accounts({ event: eventPda, buyer: holder.publicKey, recipient: holder.publicKey, waitlist: waitlistPda, tokenProgram: TOKEN_2022_PROGRAM_ID })
+      .signers([holder])
+      .rpc();
+  });
+
+  it("Queues a buyer with a prepaid deposit", async () => {
+    await program.methods
+      .joinWaitlist(eventId, ticketPrice)
+      .accounts({ event: eventPda, buyer: waitingFan.publicKey })
+      .signers([waitingFan])
+      .rpc();
+
+    const entry = await program.account.waitlistEntry.fetch(entryPda);
+    assert.isTrue(entry.position.eq(position));
+    assert.isDefined(entry.status.queued, "Entry should be queued");
+  });
+
+  it("Reserves a returned ticket for the waitlist", async () => {
+    await returnTicket(new anchor.BN(0), holder);
+
+    const waitlist = await program.account.waitlist.fetch(waitlistPda);
+    assert.isTrue(waitlist.openSlots.eqn(1), "Returned ticket should be reserved");
+
+    try {
+      await program.methods
+        .mintTicket(eventId, null, null, null)
+        .accounts({ event: eventPda, buyer: bot.publicKey, recipient: bot.publicKey, waitlist: waitlistPda, tokenProgram: TOKEN_2022_PROGRAM_ID })
+        .signers([bot])
+        .rpc();
+      assert.fail("Should have failed because the ticket is reserved for the waitlist.");
+    } catch (err) {
+      assert.equal(err.error.errorCode.code, "EventSoldOut");
+    }
+  });
+
+  it("Offers the ticket to the head of the queue, who claims it from the deposit", async () => {
+    await program.methods
+      .offerWaitlistSlot(eventId)
+      .accounts({ event: eventPda, waitlistEntry: entryPda, cranker: admin.publicKey })
+      .rpc();
+
+    const entry = await program.account.waitlistEntry.fetch(entryPda);
+    assert.isDefined(entry.status.offered, "Entry should hold an offer");
+
+    await program.methods
+      .mintTicket(eventId, null, null, null)
+      .accounts({
+        event: eventPda,
+        buyer: waitingFan.publicKey,
+        recipient: waitingFan.publicKey,
+        waitlist: waitlistPda,
+        waitlistEntry: entryPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID
+      })
+      .signers([waitingFan])
+      .rpc();
+
+    const closedEntry = await provider.connection.getAccountInfo(entryPda);
+    assert.isNull(closedEntry, "Waitlist entry should be closed after the claim.");
+
+    const waitlist = await program.account.waitlist.fetch(waitlistPda);
+    assert.isTrue(waitlist.activeOffers.eqn(0), "No offer should be outstanding");
+  });
+
+  it("Charges the payer, not the deposit, when an offer is claimed as a gift", async () => {
+    const giftedEntryPda = getWaitlistEntryPda(waitlistPda, new anchor.BN(1));
+    await program.methods
+      .joinWaitlist(eventId, ticketPrice)
+      .accounts({ event: eventPda, buyer: giftedFan.publicKey })
+      .signers([giftedFan])
+      .rpc();
+
+    await returnTicket(new anchor.BN(1), waitingFan);
+    await program.methods
+      .offerWaitlistSlot(eventId)
+      .accounts({ event: eventPda, waitlistEntry: giftedEntryPda, cranker: admin.publicKey })
+      .rpc();
+
+    const fanBalanceBefore = await provider.connection.getBalance(giftedFan.publicKey);
+    const botBalanceBefore = await provider.connection.getBalance(bot.publicKey);
+
+    await program.methods
+      .mintTicket(eventId, null, null, null)
+      .accounts({
+        event: eventPda,
+        buyer: bot.publicKey,
+        recipient: giftedFan.publicKey,
+        waitlist: waitlistPda,
+        waitlistEntry: giftedEntryPda,
+        tokenProgram: TOKEN_2022_PROGRAM_ID
+      })
+      .signers([bot])
+      .rpc();
+
+    const fanBalanceAfter = await provider.connection.getBalance(giftedFan.publicKey);
+    const botBalanceAfter = await provider.connection.getBalance(bot.publicKey);
+    assert.isTrue(fanBalanceAfter - fanBalanceBefore >= ticketPrice.toNumber(), "Entrant should get the whole deposit back");
+    assert.isTrue(botBalanceBefore - botBalanceAfter >= ticketPrice.toNumber(), "Payer should pay for the gifted ticket");
+  });
+
+  describe("Offer expiry and refunds", () => {
+    const shortEventId = new anchor.BN(24);
+    let shortEventPda: anchor.web3.PublicKey;
+    let shortWaitlistPda: anchor.web3.PublicKey;
+
+    const join = (wallet: anchor.web3.Keypair) => program.methods
+      .joinWaitlist(shortEventId, ticketPrice)
+      .accounts({ event: shortEventPda, buyer: wallet.publicKey })
+      .signers([wallet])
+      .rpc();
+
+    const offer = (position: number) => program.methods
+      .offerWaitlistSlot(shortEventId)
+      .accounts({ event: shortEventPda, waitlistEntry: getWaitlistEntryPda(shortWaitlistPda, new anchor.BN(position)), cranker: admin.publicKey })
+      .rpc();
+
+    const refund = (wallet: anchor.web3.Keypair, position: number) => program.methods
+      .refundWaitlistEntry(shortEventId, new anchor.BN(position))
+      .accounts({
+        event: shortEventPda,
+        waitlist: shortWaitlistPda,
+        waitlistEntry: getWaitlistEntryPda(shortWaitlistPda, new anchor.BN(position)),
+        buyer: wallet.publicKey
+      })
+      .signers([wallet])
+      .rpc();
+
+    before(async () => {
+      shortEventPda = getEventPda(admin.publicKey, shortEventId);
+      shortWaitlistPda = getWaitlistPda(shortEventPda);
+
+      await program.methods
+        .createEvent(
+          "Pop-up Gig",
+          "PG",
+          "A sold-out show whose waitlist offers expire quickly.",
+          "https://example.com/nft.json",
+          new anchor.BN(Math.floor(Date.now() / 1000)),
+          new anchor.BN(Math.floor(Date.now() / 1000) + 86400),
+          ticketPrice,
+          new anchor.BN(1),
+          { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+          false
+        )
+        .accounts({ event: shortEventPda, admin: admin.publicKey })
+        .rpc();
+
+      await program.methods
+        .createWaitlist(shortEventId, new anchor.BN(2))
+        .accounts({ event: shortEventPda, admin: admin.publicKey })
+        .rpc();
+
+      await program.methods
+        .mintTicket(shortEventId, null, null, null)
+        .accounts({ event: shortEventPda, buyer: holder.publicKey, recipient: holder.publicKey, waitlist: shortWaitlistPda, tokenProgram: TOKEN_2022_PROGRAM_ID })
+        .signers([holder])
+        .rpc();
+
+      for (const wallet of [slowFan, nextFan, queuedFan]) {
+        await join(wallet);
+      }
+    });
+
+    it("Moves an unclaimed offer to the next entry once it expires", async () => {
+      const ticketPda = getTicketPda(shortEventPda, new anchor.BN(0));
+      const ticket = await program.account.ticket.fetch(ticketPda);
+      await program.methods
+        .returnTicket(shortEventId, new anchor.BN(0))
+        .accounts({
+          event: shortEventPda,
+          ticket: ticketPda,
+          ticketMint: ticket.mint,
+          signer: holder.publicKey,
+          rentRecipient: holder.publicKey,
+          waitlist: shortWaitlistPda,
+          tokenProgram: TOKEN_2022_PROGRAM_ID
+        })
+        .signers([holder])
+        .rpc();
+
+      await offer(0);
+
+      const expire = () => program.methods
+        .expireWaitlistOffer(shortEventId, new anchor.BN(0))
+        .accounts({ event: shortEventPda, cranker: bot.publicKey })
+        .signers([bot])
+        .rpc();
+
+      try {
+        await expire();
+        assert.fail("Should have failed because the offer has not expired yet.");
+      } catch (err) {
+        assert.equal(err.error.errorCode.code, "WaitlistOfferActive");
+      }
+
+      await sleep(4000);
+      await expire();
+
+      const expiredEntry = await program.account.waitlistEntry.fetch(getWaitlistEntryPda(shortWaitlistPda, new anchor.BN(0)));
+      assert.isDefined(expiredEntry.status.expired, "Offer should have expired");
+
+      let waitlist = await program.account.waitlist.fetch(shortWaitlistPda);
+      assert.isTrue(waitlist.openSlots.eqn(1), "Ticket should go back to the queue");
+
+      await offer(1);
+
+      const nextEntry = await program.account.waitlistEntry.fetch(getWaitlistEntryPda(shortWaitlistPda, new anchor.BN(1)));
+      assert.isDefined(nextEntry.status.offered, "Next entry should hold the offer");
+      waitlist = await program.account.waitlist.fetch(shortWaitlistPda);
+      assert.isTrue(waitlist.activeOffers.eqn(1), "Exactly one offer should be outstanding");
+    });
+
+    it("Refunds the deposit of an expired offer", async () => {
+      const balanceBefore = await provider.connection.getBalance(slowFan.publicKey);
+      await refund(slowFan, 0);
+
+      const balanceAfter = await provider.connection.getBalance(slowFan.publicKey);
+      assert.isTrue(balanceAfter - balanceBefore >= ticketPrice.toNumber() - 10000, "Deposit should be refunded");
+      assert.isNull(await provider.connection.getAccountInfo(getWaitlistEntryPda(shortWaitlistPda, new anchor.BN(0))), "Entry should be closed");
+    });
+
+    it("Refunds a queued deposit once the event is cancelled", async () => {
+      try {
+        await refund(queuedFan, 2);
+        assert.fail("Should have failed because the event is still on sale.");
+      } catch (err) {
+        assert.equal(err.error.errorCode.code, "WaitlistRefundUnavailable");
+      }
+
+      await program.methods
+        .updateEventStatus(shortEventId, { cancelled: {} })
+        .accounts({ event: shortEventPda, admin: admin.publicKey })
+        .rpc();
+
+      const balanceBefore = await provider.connection.getBalance(queuedFan.publicKey);
+      await refund(queuedFan, 2);
+
+      const balanceAfter = await provider.connection.getBalance(queuedFan.publicKey);
+      assert.isTrue(balanceAfter - balanceBefore >= ticketPrice.toNumber() - 10000, "Queued deposit should be refunded");
+      assert.isNull(await provider.connection.getAccountInfo(getWaitlistEntryPda(shortWaitlistPda, new anchor.BN(2))), "Entry should be closed");
+    });
+  });
+});
@@ -42,7 +42,9 @@ describe("Withdraw Funds", () => {
           new anchor.BN(Math.floor(Date.now() / 1000) - 2000),
           new anchor.BN(Math.floor(Date.now() / 1000) - 1000),
           new anchor.BN(1 * anchor.web3.LAMPORTS_PER_SOL),
-          new anchor.BN(1)
+          new anchor.BN(1),
+          { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+          false
         )
         .accounts({ event: eventPda, admin: admin.publicKey })
         .rpc();
@@ -53,8 +55,8 @@ describe("Withdraw Funds", () => {
     // Mint a ticket to ensure there are funds in the vault
     try {
       await program.methods
-        .mintTicket(eventId)
-        .accounts({ event: eventPda, eventVault: eventVaultPda, buyer: buyer.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
+        .mintTicket(eventId, null, null, null)
+        .accounts({ event: eventPda, eventVault: eventVaultPda, buyer: buyer.publicKey, recipient: buyer.publicKey, tokenProgram: TOKEN_2022_PROGRAM_ID })
         .signers([buyer])
         .rpc();
     } catch (e) {
@@ -88,6 +90,15 @@ describe("Withdraw Funds", () => {
     const closedVault = await provider.connection.getAccountInfo(eventVaultPda);
     assert.isNull(closedVault, "Event vault account should have been closed.");
 
+    // Verify the event was archived
+    const [archivePda] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("event_archive"), eventPda.toBuffer()],
+      program.programId
+    );
+    const archive = await program.account.eventArchive.fetch(archivePda);
+    assert.isTrue(archive.id.eq(eventId), "Archive should record the event ID");
+    assert.isTrue(archive.admin.equals(admin.publicKey), "Archive should record the admin");
+
     // Verify the destination's balance increased
     const destinationBalanceAfter = await provider.connection.getBalance(destination.publicKey);
 
@@ -110,7 +121,9 @@ describe("Withdraw Funds", () => {
         new anchor.BN(Math.floor(Date.now() / 1000) - 1000),
         new anchor.BN(Math.floor(Date.now() / 1000) + 2000),
         new anchor.BN(1 * anchor.web3.LAMPORTS_PER_SOL),
-        new anchor.BN(1)
+        new anchor.BN(1),
+        { venue: "Main Hall", geohash: "", category: { music: {} }, minAge: 0, utcOffsetMinutes: 0 },
+        false
       )
       .accounts({ event: futureEventPda, admin: admin.publicKey })
       .rpc();